@@ -339,16 +339,43 @@ function remapPathsToCurrentUser(ports: any[]): { ports: any[]; changed: boolean
   return { ports: remapped, changed };
 }
 
+// ports.json 스키마 (src-tauri/src/schema.rs 와 동일)
+// v1: PortInfo 배열, v2: { schemaVersion: 2, ports: [...] } + worktreePaths 배열
+const PORTS_SCHEMA_VERSION = 2;
+
+function portsFromDisk(data: any): any[] {
+  if (Array.isArray(data)) return data; // v1
+  if ((data?.schemaVersion ?? 1) > PORTS_SCHEMA_VERSION) {
+    throw new Error(`ports.json schema v${data.schemaVersion} is newer than supported (v${PORTS_SCHEMA_VERSION})`);
+  }
+  return (data?.ports ?? []).map(({ worktreePaths, ...p }: any) => ({
+    ...p,
+    worktreePath: Array.isArray(worktreePaths) && worktreePaths.length > 0 ? worktreePaths.join(', ') : null,
+  }));
+}
+
+function portsToDisk(ports: any[]) {
+  return {
+    schemaVersion: PORTS_SCHEMA_VERSION,
+    ports: ports.map(({ worktreePath, ...p }: any) => ({
+      ...p,
+      worktreePaths: typeof worktreePath === 'string'
+        ? worktreePath.split(',').map((s: string) => s.trim()).filter(Boolean)
+        : [],
+    })),
+  };
+}
+
 // 포트 데이터 로드
 async function loadPortsData() {
   try {
     const file = Bun.file(PORTS_DATA_FILE);
     if (await file.exists()) {
-      const data = await file.json();
+      const data = portsFromDisk(await file.json());
       const { ports: remapped, changed } = remapPathsToCurrentUser(data);
       if (changed) {
         devLog('[Data] Auto-remapped paths to current user home dir — saving');
-        await Bun.write(PORTS_DATA_FILE, JSON.stringify(remapped, null, 2));
+        await Bun.write(PORTS_DATA_FILE, JSON.stringify(portsToDisk(remapped), null, 2));
       }
       return remapped;
     }
@@ -368,7 +395,17 @@ async function savePortsData(data: any) {
       devLog("[Data] Created app data directory:", APP_DATA_DIR);
     }
 
-    await Bun.write(PORTS_DATA_FILE, JSON.stringify(data, null, 2));
+    // 새 버전 앱이 쓴 파일은 덮어쓰지 않음
+    const existing = Bun.file(PORTS_DATA_FILE);
+    if (await existing.exists()) {
+      const version = await existing.json().then((d: any) => d?.schemaVersion ?? 1, () => 1);
+      if (version > PORTS_SCHEMA_VERSION) {
+        console.error(`[Data] ports.json schema v${version} is newer than supported — not saving`);
+        return false;
+      }
+    }
+
+    await Bun.write(PORTS_DATA_FILE, JSON.stringify(portsToDisk(data), null, 2));
     devLog("[Data] Ports data saved successfully to:", PORTS_DATA_FILE);
     return true;
   } catch (error) {
@@ -1457,21 +1494,30 @@ end try`);
       const portId = decodeURIComponent(url.pathname.slice("/api/log-content/".length));
       if (!portId) return new Response(JSON.stringify({ error: 'portId 필요' }), { status: 400, headers });
       try {
-        const offset = parseInt(url.searchParams.get('offset') ?? '0', 10);
+        const offset = parseInt(url.searchParams.get('offset') ?? '0', 10) || 0;
         const logsDir = join(APP_DATA_DIR, "logs");
         const logFile = join(logsDir, `${portId}.log`);
         if (!existsSync(logFile)) {
-          return new Response(JSON.stringify({ content: '', size: 0, exists: false }), { headers });
+          return new Response(JSON.stringify({ content: '', size: 0, exists: false, offset: 0, nextOffset: 0, truncated: false }), { headers });
         }
-        // Use byte-based offset (consistent with stat.size and Tauri's read_log_content)
+        // Tauri read_log_content 와 같은 규칙: offset 이후 바이트만, 최대 256KB, 처음/줄어든 경우 끝부분만
+        const MAX_CHUNK = 256 * 1024;
         const file = Bun.file(logFile);
-        const text = await file.text();
-        const buf = Buffer.from(text, 'utf-8');
-        const size = buf.length;
-        const content = offset > 0 && offset < buf.length
-          ? buf.slice(offset).toString('utf-8')
-          : text;
-        return new Response(JSON.stringify({ content, size, exists: true, offset }), { headers });
+        const size = file.size;
+        const truncated = offset > size;
+        const fromTail = offset === 0 || truncated;
+        const start = fromTail ? Math.max(0, size - MAX_CHUNK) : offset;
+        const end = Math.min(size, start + MAX_CHUNK);
+        const buf = Buffer.from(await file.slice(start, end).arrayBuffer());
+        let skip = 0;
+        if (fromTail && start > 0) skip = buf.indexOf(0x0a) + 1;
+        let keep = buf.length;
+        if (end < size) {
+          const nl = buf.lastIndexOf(0x0a);
+          if (nl >= skip) keep = nl + 1;
+        }
+        const content = buf.subarray(skip, keep).toString('utf-8');
+        return new Response(JSON.stringify({ content, size, exists: true, offset: start + skip, nextOffset: start + keep, truncated }), { headers });
       } catch (error: any) {
         return new Response(JSON.stringify({ error: error.message }), { status: 500, headers });
       }
@@ -0,0 +1,215 @@
+//! 서버 stdout / stderr 캡처
+//!
+//! 자식의 stdout / stderr 를 파이프로 받아 스트림마다 reader 스레드가 두 파일에 쓴다:
+//!
+//! | 파일                     | 내용                                              | 용도                         |
+//! |--------------------------|---------------------------------------------------|------------------------------|
+//! | `logs/<id>.log`          | 받은 그대로 (stdout + stderr 합침)               | `open_log` 의 `tail -f`, 검색 |
+//! | `logs/<id>.stamped.log`  | `2026-10-18T03:12:45.123Z err | <줄>` 줄 단위    | 뷰어의 stderr 필터, 시간 대조 |
+//!
+//! stamped 로그의 태그는 `out` / `err`, 그리고 run 헤더/푸터(`runs.rs`)는 `run`.
+//!
+//! 파이프는 앱이 읽어야 하므로 앱이 종료되면 더 이상 기록되지 않는다 (재시작 후 채택한 서버는
+//! 출력이 유실되고, SIGPIPE / EPIPE 를 처리하지 않는 서버는 다음 출력에서 종료될 수 있음).
+
+use std::fs;
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+use crate::{logs, procinfo, runs};
+
+/// 줄바꿈 없이 이보다 길어지면 그대로 한 줄로 기록 (진행률 표시 등)
+const MAX_LINE_BYTES: usize = 16 * 1024;
+/// 프로세스 종료 후 남은 출력을 기다리는 시간 (파이프를 물려받은 자식이 남아 있을 수 있음)
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Stream {
+    Stdout,
+    Stderr,
+    /// run 헤더 / 푸터
+    Run,
+}
+
+impl Stream {
+    fn tag(self) -> &'static str {
+        match self {
+            Stream::Stdout => "out",
+            Stream::Stderr => "err",
+            Stream::Run => "run",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "out" => Some(Stream::Stdout),
+            "err" => Some(Stream::Stderr),
+            "run" => Some(Stream::Run),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StampedLine {
+    /// UTC ISO 8601 (밀리초)
+    pub time: String,
+    pub stream: Stream,
+    pub text: String,
+}
+
+/// `read_stamped_log` 응답 — offset 의미는 `logs::LogChunk` 와 같음 (stamped 파일 기준)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StampedChunk {
+    pub lines: Vec<StampedLine>,
+    pub size: u64,
+    pub exists: bool,
+    pub offset: u64,
+    pub next_offset: u64,
+    pub truncated: bool,
+    pub fingerprint: String,
+}
+
+pub fn stamped_path(log_file: &Path) -> PathBuf {
+    log_file.with_extension("stamped.log")
+}
+
+fn open_append(path: &Path) -> Option<fs::File> {
+    fs::OpenOptions::new().create(true).append(true).open(path).ok()
+}
+
+fn stamped_line(stream: Stream, text: &[u8]) -> Vec<u8> {
+    let text = text.strip_suffix(b"\r").unwrap_or(text);
+    let mut line = format!("{} {} | ", runs::utc_timestamp(procinfo::now_millis()), stream.tag()).into_bytes();
+    line.extend_from_slice(text);
+    line.push(b'\n');
+    line
+}
+
+/// stamped 로그에 여러 줄 기록 (run 마커 등 앱이 직접 쓰는 줄)
+pub fn append_stamped(log_file: &Path, stream: Stream, text: &str) {
+    let Some(mut file) = open_append(&stamped_path(log_file)) else { return };
+    let mut out = Vec::new();
+    for line in text.lines().filter(|l| !l.is_empty()) {
+        out.extend(stamped_line(stream, line.as_bytes()));
+    }
+    let _ = file.write_all(&out);
+}
+
+/// 스트림 하나를 끝(EOF)까지 읽어 두 파일에 기록
+fn pump(mut pipe: impl Read, stream: Stream, log_file: PathBuf) {
+    let Some(mut plain) = open_append(&log_file) else { return };
+    let Some(mut stamped) = open_append(&stamped_path(&log_file)) else { return };
+    let mut buf = [0u8; 8192];
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        let n = match pipe.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        };
+        // 합친 로그는 줄을 기다리지 않고 바로 (tail -f 에 프롬프트 등이 늦게 보이지 않게)
+        let _ = plain.write_all(&buf[..n]);
+        pending.extend_from_slice(&buf[..n]);
+        let mut out = Vec::new();
+        let mut start = 0;
+        while let Some(i) = pending[start..].iter().position(|&b| b == b'\n') {
+            out.extend(stamped_line(stream, &pending[start..start + i]));
+            start += i + 1;
+        }
+        pending.drain(..start);
+        if pending.len() >= MAX_LINE_BYTES {
+            out.extend(stamped_line(stream, &pending));
+            pending.clear();
+        }
+        // 한 번의 write 로 — 다른 스트림의 줄과 섞이지 않음 (O_APPEND)
+        if !out.is_empty() {
+            let _ = stamped.write_all(&out);
+        }
+    }
+    if !pending.is_empty() {
+        let _ = stamped.write_all(&stamped_line(stream, &pending));
+    }
+}
+
+/// 실행 중인 reader 스레드들 — 종료 푸터 전에 `drain` 으로 남은 출력을 기다린다
+pub struct Capture {
+    done: mpsc::Receiver<()>,
+    readers: usize,
+}
+
+impl Capture {
+    /// `Stdio::piped()` 로 띄운 자식의 stdout / stderr 를 가져가 reader 스레드 시작
+    pub fn start(child: &mut Child, log_file: &Path) -> Capture {
+        let (tx, done) = mpsc::channel();
+        let mut readers = 0;
+        let mut spawn_reader = |pipe: Box<dyn Read + Send>, stream: Stream| {
+            let tx = tx.clone();
+            let log_file = log_file.to_path_buf();
+            readers += 1;
+            std::thread::spawn(move || {
+                pump(pipe, stream, log_file);
+                let _ = tx.send(());
+            });
+        };
+        if let Some(stdout) = child.stdout.take() {
+            spawn_reader(Box::new(stdout), Stream::Stdout);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            spawn_reader(Box::new(stderr), Stream::Stderr);
+        }
+        Capture { done, readers }
+    }
+
+    /// reader 가 모두 EOF 에 닿을 때까지 (최대 `timeout`) 대기
+    pub fn drain(self, timeout: Duration) {
+        let deadline = Instant::now() + timeout;
+        for _ in 0..self.readers {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if self.done.recv_timeout(left).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// `2026-10-18T03:12:45.123Z err | text` 파싱 — 형식이 아니면 None
+fn parse_line(line: &str) -> Option<StampedLine> {
+    let (time, rest) = line.split_once(' ')?;
+    let (tag, text) = rest.split_once(" | ").or_else(|| Some((rest.strip_suffix(" |")?, "")))?;
+    Some(StampedLine { time: time.to_string(), stream: Stream::from_tag(tag)?, text: text.to_string() })
+}
+
+/// stamped 로그를 `offset` 부터 읽어 줄 단위로 (`stream` 이 있으면 그 스트림만)
+pub fn read_stamped(
+    log_file: &Path,
+    offset: u64,
+    max_bytes: usize,
+    fingerprint: Option<&str>,
+    stream: Option<Stream>,
+) -> Result<StampedChunk, String> {
+    let chunk = logs::read_chunk(&stamped_path(log_file), offset, max_bytes, fingerprint)?;
+    let lines = chunk
+        .content
+        .lines()
+        .filter_map(parse_line)
+        .filter(|l| stream.is_none_or(|s| l.stream == s))
+        .collect();
+    Ok(StampedChunk {
+        lines,
+        size: chunk.size,
+        exists: chunk.exists,
+        offset: chunk.offset,
+        next_offset: chunk.next_offset,
+        truncated: chunk.truncated,
+        fingerprint: chunk.fingerprint,
+    })
+}
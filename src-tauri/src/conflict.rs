@@ -0,0 +1,151 @@
+//! 서버 실행 전 포트 충돌 확인
+//!
+//! PortInfo 의 포트를 이미 다른 프로세스가 점유하고 있으면 서버는 EADDRINUSE 로 죽고
+//! 원인은 로그 파일에만 남는다. `execute_command` 가 실행 전에 점유 프로세스를 조회해
+//! 구조화된 충돌 에러(JSON 문자열)를 반환하고, `onConflict` 로 처리 방법을 고를 수 있게 한다.
+//! `onConflict` 를 넘기지 않으면 (기존 호출) 충돌을 로그에만 남기고 이전처럼 그대로 실행한다.
+
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+use crate::{load_ports, port_alloc, procinfo, sockets, AppState};
+
+/// 빈 포트 탐색 범위 (요청 포트 다음부터)
+const SUGGEST_RANGE: u16 = 100;
+
+/// 충돌 시 처리 방법 (`execute_command` 의 `onConflict`)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictAction {
+    /// 실행하지 않고 충돌 에러 반환
+    Cancel,
+    /// 점유 프로세스 종료 후 실행
+    Kill,
+    /// 빈 포트로 실행 (`PORT` 환경변수 전달 + ports.json 의 포트 변경)
+    FreePort,
+}
+
+/// 포트를 점유 중인 프로세스
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortOwner {
+    pub pid: u32,
+    pub process_name: Option<String>,
+    pub command_line: Option<String>,
+    /// 앱이 띄운 다른 서버면 해당 PortInfo id / 이름
+    pub managed_port_id: Option<String>,
+    pub managed_name: Option<String>,
+}
+
+/// 충돌 에러 payload — 프론트엔드는 `kind == "port-conflict"` 로 구분해 선택지를 보여준다
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortConflict {
+    pub kind: &'static str,
+    pub port_id: String,
+    pub port: u16,
+    pub owners: Vec<PortOwner>,
+    pub suggested_port: Option<u16>,
+    pub options: Vec<ConflictAction>,
+}
+
+impl PortConflict {
+    /// 커맨드 에러 문자열 (JSON)
+    pub fn to_error(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| format!("Port {} is already in use", self.port))
+    }
+}
+
+/// `port` 를 점유한 프로세스가 있으면 충돌 정보 반환
+pub fn detect(app: &tauri::AppHandle, port_id: &str, port: u16) -> Option<PortConflict> {
+    let pids = sockets::listening_pids(port);
+    if pids.is_empty() {
+        return None;
+    }
+
+    let ports = load_ports(app.clone()).unwrap_or_default();
+    let tracked = app.state::<AppState>().processes.lock().unwrap().clone();
+    let owners: Vec<PortOwner> = pids
+        .iter()
+        .map(|&pid| {
+            // 앱이 띄운 서버는 세션 리더(PID == SID)가 추적 맵에 있다
+            let sid = procinfo::session_id(pid);
+            let managed_port_id = tracked
+                .iter()
+                .find(|(_, &tracked_pid)| tracked_pid == pid || Some(tracked_pid) == sid)
+                .map(|(id, _)| id.clone());
+            let managed_name = managed_port_id
+                .as_ref()
+                .and_then(|id| ports.iter().find(|p| &p.id == id))
+                .map(|p| p.name.clone());
+            PortOwner {
+                pid,
+                process_name: procinfo::process_name(pid),
+                command_line: procinfo::command_line(pid),
+                managed_port_id,
+                managed_name,
+            }
+        })
+        .collect();
+
+    let reserved: Vec<u16> = ports.iter().filter(|p| p.id != port_id).filter_map(|p| p.port).collect();
+    let suggested_port = suggest_port(port, &reserved);
+    let mut options = vec![ConflictAction::Kill];
+    if suggested_port.is_some() {
+        options.push(ConflictAction::FreePort);
+    }
+    options.push(ConflictAction::Cancel);
+
+    Some(PortConflict {
+        kind: "port-conflict",
+        port_id: port_id.to_string(),
+        port,
+        owners,
+        suggested_port,
+        options,
+    })
+}
+
+/// `port` 다음부터 비어 있고 다른 PortInfo 가 쓰지 않는 포트
+fn suggest_port(port: u16, reserved: &[u16]) -> Option<u16> {
+    port_alloc::first_free((1..=SUGGEST_RANGE).filter_map(|offset| port.checked_add(offset)), reserved)
+}
+
+/// 충돌한 점유 프로세스 종료 — 앱이 띄운 서버는 해당 프로젝트의 종료 설정으로, 그 외에는 해당 PID 만
+pub fn kill_owners(app: &tauri::AppHandle, conflict: &PortConflict) {
+    #[cfg(unix)]
+    {
+        use crate::shutdown;
+
+        let mut killed: Vec<u32> = Vec::new();
+        for owner in &conflict.owners {
+            if killed.contains(&owner.pid) {
+                continue;
+            }
+            match &owner.managed_port_id {
+                // 앱이 띄운 서버 — Stop 버튼과 같은 경로 (stopCommand / stopSignal / stopTimeoutMs 반영)
+                Some(managed_id) => {
+                    println!("[Conflict] Stopping {} holding port {}", managed_id, conflict.port);
+                    let (_, pids) = crate::stop_project(app, managed_id, conflict.port);
+                    killed.extend(pids);
+                }
+                None => {
+                    println!("[Conflict] Killing PID {} holding port {}", owner.pid, conflict.port);
+                    shutdown::terminate_pid(owner.pid, Default::default(), shutdown::DEFAULT_GRACE);
+                    killed.push(owner.pid);
+                }
+            }
+        }
+        // 소켓이 닫힐 때까지 잠시 대기
+        for _ in 0..20 {
+            if sockets::listening_pids(conflict.port).is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (app, conflict); // 충돌 감지는 Unix 소켓 조회 기반
+    }
+}
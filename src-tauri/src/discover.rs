@@ -0,0 +1,257 @@
+//! 폴더에서 프로젝트 후보 찾기 (모노레포 일괄 등록용)
+//!
+//! | 소스                                   | 항목 단위           | terminalCommand               |
+//! |----------------------------------------|---------------------|-------------------------------|
+//! | `docker-compose.yml` / `compose.yaml`  | 서비스              | `docker compose up <service>` |
+//! | `Procfile` / `Procfile.dev`            | 프로세스 타입       | Procfile 의 커맨드            |
+//! | package.json `workspaces` / pnpm       | dev/start 있는 패키지 | `detect_start_command`      |
+//!
+//! 포트는 compose 의 published 포트, 그 밖에는 `detect_port_in` 휴리스틱으로 채운다.
+//! YAML 은 compose / pnpm-workspace 에서 쓰는 블록 형식만 줄 단위로 읽는다.
+
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+
+use crate::{detect_port_in, detect_start_command, procinfo, PortInfo};
+
+const COMPOSE_FILES: &[&str] = &["compose.yaml", "compose.yml", "docker-compose.yaml", "docker-compose.yml"];
+const PROCFILES: &[&str] = &["Procfile.dev", "Procfile"];
+/// 패키지 포트 추정에 볼 파일 (앞쪽 우선)
+const PORT_HINT_FILES: &[&str] = &[".env.local", ".env", "package.json", "vite.config.ts", "vite.config.js"];
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Source {
+    Compose,
+    Procfile,
+    Workspace,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Discovered {
+    pub source: Source,
+    /// 근거 파일 (compose / Procfile / workspace package.json)
+    pub file: String,
+    pub port_info: PortInfo,
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// 주석 / 빈 줄 제거, 값의 따옴표 제거
+fn yaml_lines(content: &str) -> Vec<(usize, &str)> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map(|l| (indent_of(l), l.trim()))
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.split(" #").next().unwrap_or(value).trim();
+    value.trim_matches(|c| c == '"' || c == '\'')
+}
+
+/// compose 포트 표기에서 호스트(published) 포트
+///
+/// `"3000:3000"`, `"127.0.0.1:8080:80"`, `"8000-8001:8000-8001/tcp"` → 첫 호스트 포트.
+/// 컨테이너 포트만 있는 `"3000"` 은 호스트 포트가 무작위라 None.
+fn published_port(spec: &str) -> Option<u16> {
+    let spec = unquote(spec).split('/').next()?;
+    let parts: Vec<&str> = spec.rsplitn(2, ':').collect();
+    let host = parts.get(1)?;
+    let host = host.rsplit(':').next()?; // ip:host 형식
+    host.split('-').next()?.parse().ok()
+}
+
+/// `services:` 아래 서비스 이름과 published 포트
+fn compose_services(content: &str) -> Vec<(String, Option<u16>)> {
+    let lines = yaml_lines(content);
+    let Some(start) = lines.iter().position(|(indent, l)| *indent == 0 && *l == "services:") else { return Vec::new() };
+
+    let mut services: Vec<(String, Option<u16>)> = Vec::new();
+    let mut service_indent = None;
+    let mut ports_indent = None;
+    for (indent, line) in &lines[start + 1..] {
+        if *indent == 0 {
+            break;
+        }
+        let service_indent = *service_indent.get_or_insert(*indent);
+        if *indent == service_indent {
+            if let Some(name) = line.strip_suffix(':') {
+                services.push((unquote(name).to_string(), None));
+            }
+            ports_indent = None;
+            continue;
+        }
+        let Some(current) = services.last_mut() else { continue };
+        if *line == "ports:" {
+            ports_indent = Some(*indent);
+            continue;
+        }
+        match ports_indent {
+            Some(p) if *indent > p || (*indent == p && line.starts_with('-')) => {
+                if current.1.is_some() {
+                    continue;
+                }
+                // short (`- "3000:3000"`) / long (`published: 3000`) 문법
+                if let Some(spec) = line.strip_prefix('-').map(str::trim).filter(|s| !s.contains(": ") && !s.is_empty()) {
+                    current.1 = published_port(spec);
+                } else if let Some(value) = line.trim_start_matches('-').trim().strip_prefix("published:") {
+                    current.1 = unquote(value).parse().ok();
+                }
+            }
+            Some(_) => ports_indent = None,
+            None => {}
+        }
+    }
+    services
+}
+
+/// Procfile `name: command` 목록
+fn procfile_entries(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let (name, command) = l.split_once(':')?;
+            let name = name.trim();
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                return None;
+            }
+            Some((name.to_string(), command.trim().to_string()))
+        })
+        .filter(|(_, command)| !command.is_empty())
+        .collect()
+}
+
+/// package.json `workspaces` (배열 또는 `{ packages: [...] }`) + pnpm-workspace.yaml `packages:`
+fn workspace_patterns(folder: &Path) -> Vec<String> {
+    let mut patterns = Vec::new();
+    if let Ok(pkg) = std::fs::read_to_string(folder.join("package.json"))
+        .map_err(|e| e.to_string())
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).map_err(|e| e.to_string()))
+    {
+        let workspaces = pkg.get("workspaces");
+        let list = workspaces.and_then(|w| w.as_array()).or_else(|| workspaces?.get("packages")?.as_array());
+        patterns.extend(list.into_iter().flatten().filter_map(|v| v.as_str().map(str::to_string)));
+    }
+    if let Ok(content) = std::fs::read_to_string(folder.join("pnpm-workspace.yaml")) {
+        let lines = yaml_lines(&content);
+        if let Some(start) = lines.iter().position(|(indent, l)| *indent == 0 && *l == "packages:") {
+            patterns.extend(
+                lines[start + 1..]
+                    .iter()
+                    .take_while(|(indent, _)| *indent > 0)
+                    .filter_map(|(_, l)| l.strip_prefix('-'))
+                    .map(|p| unquote(p).to_string()),
+            );
+        }
+    }
+    patterns
+}
+
+/// `apps/*`, `packages/**`, `apps/web` 형식만 지원 (제외 패턴 `!` 은 무시)
+fn expand_pattern(folder: &Path, pattern: &str) -> Vec<PathBuf> {
+    if pattern.starts_with('!') {
+        return Vec::new();
+    }
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+    let base = pattern.trim_end_matches("/**").trim_end_matches("/*");
+    if base == pattern {
+        return vec![folder.join(pattern)];
+    }
+    let Ok(entries) = std::fs::read_dir(folder.join(base)) else { return Vec::new() };
+    let mut dirs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir() && p.file_name().is_some_and(|n| n != "node_modules" && !n.to_string_lossy().starts_with('.')))
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn has_dev_script(pkg: &serde_json::Value) -> bool {
+    pkg.get("scripts").is_some_and(|s| s.get("dev").is_some() || s.get("start").is_some())
+}
+
+fn port_hint(dir: &Path) -> Option<u16> {
+    PORT_HINT_FILES
+        .iter()
+        .filter_map(|f| std::fs::read_to_string(dir.join(f)).ok())
+        .find_map(|content| detect_port_in(&content))
+}
+
+fn entry(id: String, name: String, folder: &Path, port: Option<u16>, command: Option<String>) -> PortInfo {
+    PortInfo {
+        id,
+        name,
+        port,
+        folder_path: Some(folder.to_string_lossy().to_string()),
+        terminal_command: command,
+        ..Default::default()
+    }
+}
+
+/// 폴더 안의 compose / Procfile / workspace 에서 프로젝트 후보 목록
+pub fn discover(folder: &Path) -> Result<Vec<Discovered>, String> {
+    if !folder.is_dir() {
+        return Err(format!("Folder not found: {}", folder.display()));
+    }
+    let folder_name = folder.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let stamp = procinfo::now_millis();
+    let mut found = Vec::new();
+    let mut counter = 0;
+    let mut next_id = || {
+        counter += 1;
+        format!("discovered-{}-{}", stamp, counter)
+    };
+
+    if let Some(file) = COMPOSE_FILES.iter().map(|f| folder.join(f)).find(|p| p.is_file()) {
+        let content = std::fs::read_to_string(&file).map_err(|e| e.to_string())?;
+        for (service, port) in compose_services(&content) {
+            let mut info = entry(
+                next_id(),
+                format!("{} {}", folder_name, service),
+                folder,
+                port,
+                Some(format!("docker compose up {}", service)),
+            );
+            info.stop_command = Some(format!("docker compose stop {}", service));
+            found.push(Discovered { source: Source::Compose, file: file.to_string_lossy().to_string(), port_info: info });
+        }
+    }
+
+    if let Some(file) = PROCFILES.iter().map(|f| folder.join(f)).find(|p| p.is_file()) {
+        let content = std::fs::read_to_string(&file).map_err(|e| e.to_string())?;
+        for (name, command) in procfile_entries(&content) {
+            let port = detect_port_in(&command);
+            let info = entry(next_id(), format!("{} {}", folder_name, name), folder, port, Some(command));
+            found.push(Discovered { source: Source::Procfile, file: file.to_string_lossy().to_string(), port_info: info });
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for pattern in workspace_patterns(folder) {
+        for dir in expand_pattern(folder, &pattern) {
+            let pkg_file = dir.join("package.json");
+            let Some(pkg) = std::fs::read_to_string(&pkg_file).ok().and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok()) else { continue };
+            // 라이브러리 패키지(dev/start 없음)는 제외
+            if !has_dev_script(&pkg) || !seen.insert(dir.clone()) {
+                continue;
+            }
+            let name = pkg
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default());
+            let command = detect_start_command(dir.to_string_lossy().to_string());
+            let info = entry(next_id(), name, &dir, port_hint(&dir), command);
+            found.push(Discovered { source: Source::Workspace, file: pkg_file.to_string_lossy().to_string(), port_info: info });
+        }
+    }
+    Ok(found)
+}
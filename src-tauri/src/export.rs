@@ -0,0 +1,246 @@
+//! 포트 목록 내보내기 (JSON / YAML / TOML / CSV)
+//!
+//! JSON / YAML / TOML 은 ports.json 과 같은 `{ schemaVersion, ports }` 문서 (JSON / TOML 은 다시 불러오기 가능),
+//! CSV 는 스프레드시트용으로 한 항목 = 한 행 (중첩 값은 JSON 문자열).
+//!
+//! `portable` 이면 다른 기기와 공유할 수 있게 기기 전용 값을 정리한다:
+//! - `sourceDeviceId`, `isRunning` 제거
+//! - folderPath / worktreePath 는 workspace root 기준 상대경로, 그 밖의 홈 디렉토리 아래 경로는 `~/...`
+//! - commandPath 는 raw 커맨드와 구분되도록 `~/...` 만 (상대경로로 바꾸지 않음)
+//!
+//! 불러올 때 `resolve_paths` 가 반대로 받는 기기의 root / 홈 디렉토리 기준 절대경로로 되돌린다.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{schema, PortInfo};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+    Yaml,
+    Toml,
+    Csv,
+}
+
+impl ExportFormat {
+    /// 확장자로 형식 추정 (모르면 JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("yaml" | "yml") => ExportFormat::Yaml,
+            Some("toml") => ExportFormat::Toml,
+            Some("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Json,
+        }
+    }
+}
+
+/// CSV 앞쪽에 고정할 컬럼 (나머지는 이름순)
+const CSV_LEADING: &[&str] = &["id", "name", "port", "category", "description", "folderPath", "commandPath", "worktreePath"];
+
+pub fn render(ports: &[PortInfo], format: ExportFormat) -> Result<String, String> {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&schema::to_document(ports)?).map_err(|e| e.to_string()),
+        ExportFormat::Yaml => {
+            let mut out = String::new();
+            yaml_block(&schema::to_document(ports)?, 0, &mut out);
+            Ok(out)
+        }
+        ExportFormat::Toml => toml::to_string_pretty(&strip_nulls(schema::to_document(ports)?)).map_err(|e| e.to_string()),
+        ExportFormat::Csv => csv(ports),
+    }
+}
+
+/// 기기 전용 값 정리 (`workspace_root` 가 없으면 홈 디렉토리만 `~` 로)
+pub fn make_portable(ports: &mut [PortInfo], workspace_root: Option<&str>) {
+    let home = home_dir();
+    let root = non_empty_root(workspace_root);
+    let to_home = |path: &str| -> String {
+        match home.as_deref().and_then(|h| Path::new(path).strip_prefix(h).ok()) {
+            Some(rel) => format!("~/{}", rel.to_string_lossy()).trim_end_matches('/').to_string(),
+            None => path.to_string(),
+        }
+    };
+    let to_root = |path: &str| -> String {
+        match root.and_then(|r| Path::new(path).strip_prefix(r).ok()) {
+            Some(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+            Some(rel) => rel.to_string_lossy().to_string(),
+            None => to_home(path),
+        }
+    };
+
+    for p in ports.iter_mut() {
+        p.source_device_id = None;
+        p.is_running = false;
+        map_path(&mut p.folder_path, |s| if s.starts_with('/') { to_root(s) } else { s.to_string() });
+        map_path(&mut p.command_path, |s| if s.starts_with('/') { to_home(s) } else { s.to_string() });
+        map_worktrees(&mut p.worktree_path, |s| if s.starts_with('/') { to_root(s) } else { s.to_string() });
+    }
+}
+
+/// `make_portable` 의 반대 — `~/...` 는 홈, 상대경로(folderPath / worktreePath)는 `workspace_root` 기준 절대경로로
+pub fn resolve_paths(ports: &mut [PortInfo], workspace_root: Option<&str>) {
+    let home = home_dir();
+    let root = non_empty_root(workspace_root);
+    let from_home = |path: &str| -> Option<String> {
+        let home = home.as_deref()?;
+        if path == "~" {
+            Some(home.to_string())
+        } else {
+            path.strip_prefix("~/").map(|rest| format!("{}/{}", home.trim_end_matches('/'), rest))
+        }
+    };
+    let from_root = |path: &str| -> String {
+        if let Some(resolved) = from_home(path) {
+            return resolved;
+        }
+        match root {
+            Some(root) if !path.starts_with('/') && !path.contains("://") => {
+                Path::new(root).join(path).to_string_lossy().trim_end_matches("/.").to_string()
+            }
+            _ => path.to_string(),
+        }
+    };
+
+    for p in ports.iter_mut() {
+        map_path(&mut p.folder_path, from_root);
+        map_path(&mut p.command_path, |s| from_home(s).unwrap_or_else(|| s.to_string()));
+        map_worktrees(&mut p.worktree_path, from_root);
+    }
+}
+
+fn home_dir() -> Option<String> {
+    std::env::var("HOME").ok().filter(|h| !h.is_empty())
+}
+
+fn non_empty_root(root: Option<&str>) -> Option<&str> {
+    root.map(|r| r.trim_end_matches('/')).filter(|r| !r.is_empty())
+}
+
+fn map_path(field: &mut Option<String>, f: impl Fn(&str) -> String) {
+    if let Some(path) = field.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        *field = Some(f(path));
+    }
+}
+
+/// worktreePath 는 콤마 구분 목록
+fn map_worktrees(field: &mut Option<String>, f: impl Fn(&str) -> String) {
+    if let Some(paths) = field.as_deref() {
+        let mapped: Vec<String> = paths.split(',').map(str::trim).filter(|s| !s.is_empty()).map(f).collect();
+        *field = Some(mapped.join(", "));
+    }
+}
+
+/// TOML 에는 null 이 없으므로 키째 제거
+fn strip_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(
+            obj.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().filter(|v| !v.is_null()).map(strip_nulls).collect()),
+        other => other,
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    match value {
+        Value::Object(obj) => obj.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => true,
+    }
+}
+
+/// 스칼라 / 빈 컨테이너 — 문자열은 JSON 따옴표 형식 (YAML double-quoted 와 호환)
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn yaml_key(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// 블록 스타일 YAML (들여쓰기 2칸)
+fn yaml_block(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (key, v) in obj {
+                if is_scalar(v) {
+                    out.push_str(&format!("{}{}: {}\n", pad, yaml_key(key), yaml_scalar(v)));
+                } else {
+                    out.push_str(&format!("{}{}:\n", pad, yaml_key(key)));
+                    yaml_block(v, indent + 2, out);
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                if is_scalar(item) {
+                    out.push_str(&format!("{}- {}\n", pad, yaml_scalar(item)));
+                } else {
+                    // 중첩 블록의 첫 줄 들여쓰기를 "- " 로 바꿔 같은 줄에 시작
+                    let mut nested = String::new();
+                    yaml_block(item, indent + 2, &mut nested);
+                    out.push_str(&pad);
+                    out.push_str("- ");
+                    out.push_str(&nested[indent + 2..]);
+                }
+            }
+        }
+        other => out.push_str(&format!("{}{}\n", pad, yaml_scalar(other))),
+    }
+}
+
+fn csv_cell(value: Option<&Value>) -> String {
+    let raw = match value {
+        None | Some(Value::Null) => return String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    if raw.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", raw.replace('"', "\"\""))
+    } else {
+        raw
+    }
+}
+
+fn csv(ports: &[PortInfo]) -> Result<String, String> {
+    let rows: Vec<Map<String, Value>> = ports
+        .iter()
+        .map(|p| match serde_json::to_value(p) {
+            Ok(Value::Object(obj)) => Ok(obj),
+            Ok(_) => Err("PortInfo did not serialize to an object".to_string()),
+            Err(e) => Err(e.to_string()),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let others: BTreeSet<&str> = rows
+        .iter()
+        .flat_map(|r| r.keys().map(String::as_str))
+        .filter(|k| !CSV_LEADING.contains(k))
+        .collect();
+    let columns: Vec<&str> = CSV_LEADING.iter().copied().chain(others).collect();
+
+    let mut out = columns.join(",");
+    out.push('\n');
+    for row in &rows {
+        let cells: Vec<String> = columns.iter().map(|c| csv_cell(row.get(*c))).collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    Ok(out)
+}
@@ -0,0 +1,296 @@
+//! 서버 헬스 체크 — "포트가 열려 있음" 이상의 상태 확인
+//!
+//! PortInfo 의 `healthCheck` 로 프로브를 선언하면 백그라운드 스레드가 `intervalMs` 마다 실행하고,
+//! 상태(healthy / degraded / down)가 바뀔 때 `health-changed` 이벤트를 보낸다.
+//!
+//! | type      | healthy                         | degraded                       | down              |
+//! |-----------|---------------------------------|--------------------------------|-------------------|
+//! | `tcp`     | 접속 성공                       | —                              | 접속 실패         |
+//! | `http`    | 기대한 status (기본 2xx/3xx)    | 접속은 되지만 status 불일치    | 접속 실패         |
+//! | `command` | exit 0                          | 실패/타임아웃, 포트는 열려 있음 | 실패, 포트 닫힘   |
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager};
+
+use crate::procinfo::{self, now_secs};
+use crate::{cached_ports, sockets, AppState, PortInfo};
+
+const TICK: Duration = Duration::from_secs(1);
+const POLL: Duration = Duration::from_millis(50);
+
+fn default_path() -> String { "/".to_string() }
+fn default_interval_ms() -> u64 { 5_000 }
+fn default_timeout_ms() -> u64 { 2_000 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Probe {
+    /// 포트 TCP 접속
+    Tcp,
+    /// `GET <path>` 후 status 확인 (expectedStatus 없으면 200~399)
+    #[serde(rename_all = "camelCase")]
+    Http {
+        #[serde(default = "default_path")]
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expected_status: Option<u16>,
+    },
+    /// 셸 커맨드 (folderPath 에서 실행, exit 0 이면 healthy)
+    Command { command: String },
+}
+
+/// PortInfo 별 헬스 프로브 설정
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthCheck {
+    #[serde(flatten)]
+    pub probe: Probe,
+    #[serde(default = "default_interval_ms")]
+    pub interval_ms: u64,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Down,
+}
+
+/// `health-changed` 이벤트 payload 겸 `get_health_status` / `check_health` 응답
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    pub port_id: String,
+    pub status: HealthStatus,
+    /// 프로브 소요 시간 (down 이면 None)
+    pub latency_ms: Option<u64>,
+    /// 실패 사유 / HTTP status 등
+    pub message: Option<String>,
+    pub checked_at: u64,
+}
+
+/// 마지막 헬스 체크 결과 보관
+#[derive(Default)]
+pub struct HealthMonitor {
+    reports: Mutex<HashMap<String, HealthReport>>,
+}
+
+impl HealthMonitor {
+    pub fn reports(&self) -> Vec<HealthReport> {
+        self.reports.lock().unwrap().values().cloned().collect()
+    }
+
+    /// 결과 기록 — 상태가 바뀌었으면 `health-changed` 이벤트 전송
+    pub fn record(&self, app: &tauri::AppHandle, report: HealthReport) {
+        let mut reports = self.reports.lock().unwrap();
+        let changed = reports.get(&report.port_id).map(|r| r.status) != Some(report.status);
+        if changed {
+            println!("[Health] {} is {:?} ({:?})", report.port_id, report.status, report.message);
+            let _ = app.emit("health-changed", report.clone());
+        }
+        reports.insert(report.port_id.clone(), report);
+    }
+
+    /// healthCheck 설정이 없어진 항목 제거
+    fn retain(&self, port_ids: &[&str]) {
+        self.reports.lock().unwrap().retain(|id, _| port_ids.contains(&id.as_str()));
+    }
+}
+
+/// PortInfo 에 선언된 프로브를 한 번 실행
+pub fn check(port_info: &PortInfo, check: &HealthCheck) -> HealthReport {
+    let timeout = Duration::from_millis(check.timeout_ms.max(1));
+    let started = Instant::now();
+    let (status, message) = match (&check.probe, port_info.port) {
+        (Probe::Tcp, Some(port)) => match connect(port, timeout) {
+            Ok(_) => (HealthStatus::Healthy, None),
+            Err(e) => (HealthStatus::Down, Some(e.to_string())),
+        },
+        (Probe::Http { path, expected_status }, Some(port)) => http_probe(port, path, *expected_status, timeout),
+        (Probe::Command { command }, port) => command_probe(command, port_info.folder_path.as_deref(), port, timeout),
+        (_, None) => (HealthStatus::Down, Some("No port configured".to_string())),
+    };
+    HealthReport {
+        port_id: port_info.id.clone(),
+        status,
+        latency_ms: (status != HealthStatus::Down).then(|| started.elapsed().as_millis() as u64),
+        message,
+        checked_at: now_secs(),
+    }
+}
+
+/// localhost 접속 — IPv4 먼저, 실패하면 IPv6 (Node 17+ 의 Vite 는 `::1` 에만 바인딩하기도 함)
+fn connect(port: u16, timeout: Duration) -> std::io::Result<TcpStream> {
+    let v4 = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let v6 = SocketAddr::from((Ipv6Addr::LOCALHOST, port));
+    TcpStream::connect_timeout(&v4, timeout).or_else(|_| TcpStream::connect_timeout(&v6, timeout))
+}
+
+/// `GET <path>` 의 HTTP status — 접속 실패는 down, 응답 이상은 degraded 로 분류해 반환
+fn http_status(port: u16, path: &str, timeout: Duration) -> Result<u16, (HealthStatus, String)> {
+    let mut stream = connect(port, timeout).map_err(|e| (HealthStatus::Down, e.to_string()))?;
+    let _ = stream.set_read_timeout(Some(timeout));
+    let _ = stream.set_write_timeout(Some(timeout));
+
+    let path = if path.starts_with('/') { path.to_string() } else { format!("/{}", path) };
+    let request = format!("GET {} HTTP/1.0\r\nHost: localhost:{}\r\nConnection: close\r\n\r\n", path, port);
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| (HealthStatus::Degraded, format!("Request failed: {}", e)))?;
+
+    // status line 만 필요 — 응답 앞부분만 읽음
+    let mut buf = [0u8; 512];
+    let mut len = 0;
+    while len < buf.len() {
+        match stream.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => {
+                len += n;
+                if buf[..len].contains(&b'\n') {
+                    break;
+                }
+            }
+            Err(e) => return Err((HealthStatus::Degraded, format!("No response: {}", e))),
+        }
+    }
+    let head = String::from_utf8_lossy(&buf[..len]);
+    head.lines()
+        .next()
+        .filter(|line| line.starts_with("HTTP/"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| (HealthStatus::Degraded, "Invalid HTTP response".to_string()))
+}
+
+fn http_probe(port: u16, path: &str, expected_status: Option<u16>, timeout: Duration) -> (HealthStatus, Option<String>) {
+    match (http_status(port, path, timeout), expected_status) {
+        (Err((status, message)), _) => (status, Some(message)),
+        (Ok(code), Some(expected)) if code != expected => {
+            (HealthStatus::Degraded, Some(format!("HTTP {} (expected {})", code, expected)))
+        }
+        (Ok(code), None) if !(200..400).contains(&code) => (HealthStatus::Degraded, Some(format!("HTTP {}", code))),
+        (Ok(code), _) => (HealthStatus::Healthy, Some(format!("HTTP {}", code))),
+    }
+}
+
+fn command_probe(command: &str, folder_path: Option<&str>, port: Option<u16>, timeout: Duration) -> (HealthStatus, Option<String>) {
+    // 실패 시: 포트가 열려 있으면 서버는 떠 있지만 비정상 → degraded
+    let failed = |message: String| {
+        let bound = port.map(|p| !sockets::listeners(p).is_empty()).unwrap_or(false);
+        (if bound { HealthStatus::Degraded } else { HealthStatus::Down }, Some(message))
+    };
+
+    #[cfg(unix)]
+    let mut cmd = {
+        let mut cmd = Command::new("bash");
+        cmd.arg("-c").arg(command).env("PATH", crate::supervisor::shell_path());
+        use std::os::unix::process::CommandExt;
+        unsafe {
+            cmd.pre_exec(|| {
+                // 타임아웃 시 프로브가 띄운 자식까지 함께 정리
+                libc::setsid();
+                Ok(())
+            });
+        }
+        cmd
+    };
+    #[cfg(not(unix))]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    };
+    cmd.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+    if let Some(fp) = folder_path.filter(|fp| !fp.is_empty()) {
+        cmd.current_dir(fp);
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => return failed(format!("Failed to run health command: {}", e)),
+    };
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return (HealthStatus::Healthy, None),
+            Ok(Some(status)) => return failed(format!("Health command exited with {}", status)),
+            Ok(None) if Instant::now() >= deadline => {
+                #[cfg(unix)]
+                crate::shutdown::kill_session(child.id());
+                let _ = child.kill();
+                let _ = child.wait();
+                return failed(format!("Health command timed out after {:?}", timeout));
+            }
+            Ok(None) => std::thread::sleep(POLL),
+            Err(e) => return failed(format!("Failed to wait for health command: {}", e)),
+        }
+    }
+}
+
+/// 서버가 요청을 받을 수 있을 때까지 대기 (execute_command 의 `waitReady`)
+///
+/// - healthCheck 가 http 면 해당 경로가 2xx (expectedStatus 가 있으면 그 값) 를 반환할 때
+/// - 그 외에는 포트가 TCP 접속을 받을 때
+///
+/// `pid` 세션이 통째로 종료되면 타임아웃 전에 실패로 반환한다. 성공 시 걸린 시간 반환.
+pub fn wait_ready(port: u16, health_check: Option<&HealthCheck>, pid: u32, timeout: Duration) -> Result<Duration, String> {
+    const READY_POLL: Duration = Duration::from_millis(250);
+    let probe_timeout = Duration::from_secs(1);
+    let started = Instant::now();
+    loop {
+        let ready = match health_check.map(|h| &h.probe) {
+            Some(Probe::Http { path, expected_status }) => match http_status(port, path, probe_timeout) {
+                Ok(code) => expected_status.map_or((200..300).contains(&code), |expected| code == expected),
+                Err(_) => false,
+            },
+            _ => connect(port, probe_timeout).is_ok(),
+        };
+        if ready {
+            return Ok(started.elapsed());
+        }
+        if !procinfo::is_alive(pid) && procinfo::session_members(pid).is_empty() {
+            return Err(format!("Process {} exited before port {} became ready", pid, port));
+        }
+        if started.elapsed() >= timeout {
+            return Err(format!("Port {} was not ready after {:?}", port, timeout));
+        }
+        std::thread::sleep(READY_POLL);
+    }
+}
+
+/// 백그라운드 체크 스레드 — 각 PortInfo 의 intervalMs 마다 프로브 실행
+pub fn start(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_run: HashMap<String, Instant> = HashMap::new();
+        loop {
+            let ports = cached_ports(&app);
+            let state = app.state::<AppState>();
+            let configured: Vec<&PortInfo> = ports.iter().filter(|p| p.health_check.is_some()).collect();
+
+            for port_info in &configured {
+                let Some(health_check) = port_info.health_check.as_ref() else { continue };
+                let interval = Duration::from_millis(health_check.interval_ms.max(TICK.as_millis() as u64));
+                let due = last_run.get(&port_info.id).is_none_or(|t| t.elapsed() >= interval);
+                if !due {
+                    continue;
+                }
+                last_run.insert(port_info.id.clone(), Instant::now());
+                state.health.record(&app, check(port_info, health_check));
+            }
+
+            let ids: Vec<&str> = configured.iter().map(|p| p.id.as_str()).collect();
+            last_run.retain(|id, _| ids.contains(&id.as_str()));
+            state.health.retain(&ids);
+            std::thread::sleep(TICK);
+        }
+    });
+}
@@ -0,0 +1,171 @@
+//! ports.json 저장 이력 (되돌리기용)
+//!
+//! `save_ports` 가 성공할 때마다 저장된 목록을 `history/ports-<epoch ms>.json` 으로 남기고,
+//! 최근 `MAX_SNAPSHOTS` 개만 유지한다. 잘못된 Supabase pull 이나 일괄 편집을
+//! 스냅샷 목록 → 현재와 비교 → 복원 순서로 되돌릴 수 있다.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::Manager;
+
+use crate::{persist, procinfo, schema, PortInfo};
+
+const MAX_SNAPSHOTS: usize = 50;
+const PREFIX: &str = "ports-";
+const SUFFIX: &str = ".json";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    /// 스냅샷 id (저장 시각 epoch ms)
+    pub id: String,
+    pub created_at: u64,
+    /// 항목 수
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedEntry {
+    pub id: String,
+    pub name: String,
+    /// 값이 다른 필드 (JSON 키 이름)
+    pub fields: Vec<String>,
+}
+
+/// 스냅샷 → 현재 비교 결과 (`removed` 는 복원하면 되살아나는 항목)
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+    pub added: Vec<EntryRef>,
+    pub removed: Vec<EntryRef>,
+    pub changed: Vec<ChangedEntry>,
+}
+
+fn history_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    Ok(app.path().app_data_dir().map_err(|e| e.to_string())?.join("history"))
+}
+
+fn snapshot_path(app: &tauri::AppHandle, id: &str) -> Result<PathBuf, String> {
+    // id 는 숫자만 허용 (경로 조작 방지)
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid snapshot id: {}", id));
+    }
+    Ok(history_dir(app)?.join(format!("{}{}{}", PREFIX, id, SUFFIX)))
+}
+
+/// 오래된 것부터 정렬된 스냅샷 id 목록
+fn snapshot_ids(app: &tauri::AppHandle) -> Vec<u64> {
+    let Ok(dir) = history_dir(app) else { return Vec::new() };
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut ids: Vec<u64> = entries
+        .flatten()
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.strip_prefix(PREFIX)?.strip_suffix(SUFFIX)?.parse().ok()
+        })
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+fn read_snapshot(app: &tauri::AppHandle, id: &str) -> Result<Vec<PortInfo>, String> {
+    let path = snapshot_path(app, id)?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Snapshot {} not found: {}", id, e))?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// 저장된 목록을 스냅샷으로 기록 (직전 스냅샷과 같으면 생략) 후 오래된 스냅샷 정리
+pub fn record(app: &tauri::AppHandle, ports: &[PortInfo]) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(ports).map_err(|e| e.to_string())?;
+    let ids = snapshot_ids(app);
+    if let Some(last) = ids.last() {
+        let last_content = fs::read_to_string(snapshot_path(app, &last.to_string())?).unwrap_or_default();
+        if last_content == content {
+            return Ok(());
+        }
+    }
+
+    // 같은 ms 에 두 번 저장되면 id 가 겹치지 않도록 증가
+    let id = procinfo::now_millis().max(ids.last().map_or(0, |l| l + 1));
+    persist::write_atomic(&snapshot_path(app, &id.to_string())?, content.as_bytes())?;
+
+    let mut ids = ids;
+    ids.push(id);
+    let excess = ids.len().saturating_sub(MAX_SNAPSHOTS);
+    for old in &ids[..excess] {
+        let _ = fs::remove_file(snapshot_path(app, &old.to_string())?);
+    }
+    Ok(())
+}
+
+/// 첫 스냅샷 전에 기존 ports.json 을 기준 스냅샷으로 남김 (이력 도입 전 상태도 되돌릴 수 있도록)
+pub fn record_baseline(app: &tauri::AppHandle, ports_file: &Path) {
+    if !snapshot_ids(app).is_empty() {
+        return;
+    }
+    if let Ok(Some(existing)) = schema::read_ports(ports_file) {
+        if let Err(e) = record(app, &existing) {
+            println!("[History] Failed to record baseline snapshot: {}", e);
+        }
+    }
+}
+
+/// 최신순 스냅샷 목록
+pub fn list(app: &tauri::AppHandle) -> Vec<Snapshot> {
+    snapshot_ids(app)
+        .into_iter()
+        .rev()
+        .map(|id| Snapshot {
+            id: id.to_string(),
+            created_at: id,
+            count: read_snapshot(app, &id.to_string()).map(|p| p.len()).unwrap_or(0),
+        })
+        .collect()
+}
+
+pub fn load(app: &tauri::AppHandle, id: &str) -> Result<Vec<PortInfo>, String> {
+    read_snapshot(app, id)
+}
+
+/// 스냅샷과 현재 목록 비교 (id 기준)
+pub fn diff(snapshot: &[PortInfo], current: &[PortInfo]) -> SnapshotDiff {
+    let to_value = |p: &PortInfo| serde_json::to_value(p).unwrap_or(Value::Null);
+    let entry = |p: &PortInfo| EntryRef { id: p.id.clone(), name: p.name.clone() };
+
+    let mut result = SnapshotDiff::default();
+    for old in snapshot {
+        match current.iter().find(|p| p.id == old.id) {
+            None => result.removed.push(entry(old)),
+            Some(new) => {
+                let (old_value, new_value) = (to_value(old), to_value(new));
+                let (Some(old_obj), Some(new_obj)) = (old_value.as_object(), new_value.as_object()) else { continue };
+                let keys: BTreeSet<&String> = old_obj.keys().chain(new_obj.keys()).collect();
+                let fields: Vec<String> = keys
+                    .into_iter()
+                    .filter(|k| old_obj.get(*k) != new_obj.get(*k))
+                    .cloned()
+                    .collect();
+                if !fields.is_empty() {
+                    result.changed.push(ChangedEntry { id: new.id.clone(), name: new.name.clone(), fields });
+                }
+            }
+        }
+    }
+    for new in current {
+        if !snapshot.iter().any(|p| p.id == new.id) {
+            result.added.push(entry(new));
+        }
+    }
+    result
+}
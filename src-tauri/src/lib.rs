@@ -6,7 +6,29 @@ use serde::{Deserialize, Serialize};
 use tauri::{State, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+mod capture;
+mod conflict;
+mod discover;
+mod export;
+mod health;
+mod history;
+mod logs;
+mod logscan;
+mod merge;
+mod persist;
+mod port_alloc;
+mod procinfo;
+mod runs;
+mod schema;
+mod shutdown;
+mod sockets;
+mod stores;
+mod supervisor;
+mod tracked;
+mod validation;
+mod watcher;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct PortInfo {
     id: String,
     name: String,
@@ -36,10 +58,53 @@ struct PortInfo {
     terminal_command: Option<String>,
     #[serde(rename = "sourceDeviceId", default, skip_serializing_if = "Option::is_none")]
     source_device_id: Option<String>,
+    #[serde(rename = "restartPolicy", default, skip_serializing_if = "Option::is_none")]
+    restart_policy: Option<supervisor::RestartPolicy>,
+    /// 종료 시 처음 보낼 시그널 (TERM / INT / HUP / QUIT, 기본 TERM)
+    #[serde(rename = "stopSignal", default, skip_serializing_if = "Option::is_none")]
+    stop_signal: Option<shutdown::StopSignal>,
+    /// 종료 시그널 후 SIGKILL 까지 대기 시간 (기본 200ms)
+    #[serde(rename = "stopTimeoutMs", default, skip_serializing_if = "Option::is_none")]
+    stop_timeout_ms: Option<u64>,
+    /// 시그널 전에 실행할 종료 커맨드 (예: `docker compose down`, folderPath 에서 실행)
+    #[serde(rename = "stopCommand", default, skip_serializing_if = "Option::is_none")]
+    stop_command: Option<String>,
+    /// 헬스 프로브 (tcp / http / command) — 없으면 포트 바인딩 여부만 표시
+    #[serde(rename = "healthCheck", default, skip_serializing_if = "Option::is_none")]
+    health_check: Option<health::HealthCheck>,
+    /// 로그 rotation / 보존 설정 — 없으면 기본값 (10MB / 24시간 / 5개 / 14일)
+    #[serde(rename = "logRetention", default, skip_serializing_if = "Option::is_none")]
+    log_retention: Option<logs::LogRetention>,
 }
 
 struct AppState {
+    /// port_id → 앱이 띄운 서버 PID (`tracked` 모듈이 processes.json 에 영속화)
     processes: Mutex<HashMap<String, u32>>,
+    supervisor: supervisor::Supervisor,
+    health: health::HealthMonitor,
+    ports: schema::PortsCache,
+    log_errors: logscan::LogErrors,
+}
+
+/// ports.json 에서 id 로 PortInfo 조회 (커맨드 인자로 넘어오지 않는 설정 값 참조용)
+fn find_port_info(app_handle: &tauri::AppHandle, port_id: &str) -> Option<PortInfo> {
+    load_ports(app_handle.clone())
+        .ok()?
+        .into_iter()
+        .find(|p| p.id == port_id)
+}
+
+/// 백그라운드 루프용 포트 목록 — ports.json 이 저장된 뒤에만 다시 읽음
+fn cached_ports(app_handle: &tauri::AppHandle) -> Vec<PortInfo> {
+    let Ok(app_data_dir) = app_handle.path().app_data_dir() else { return Vec::new() };
+    let state = app_handle.state::<AppState>();
+    match state.ports.get(&app_data_dir.join("ports.json")) {
+        Ok(ports) => ports,
+        Err(e) => {
+            println!("[Ports] Failed to load ports.json: {}", e);
+            Vec::new()
+        }
+    }
 }
 
 #[tauri::command]
@@ -56,19 +121,32 @@ fn load_ports(app_handle: tauri::AppHandle) -> Result<Vec<PortInfo>, String> {
 
     let ports_file = app_data_dir.join("ports.json");
 
-    if ports_file.exists() {
-        let content = fs::read_to_string(&ports_file)
-            .map_err(|e| e.to_string())?;
-        let ports: Vec<PortInfo> = serde_json::from_str(&content)
-            .map_err(|e| e.to_string())?;
-        return Ok(ports);
-    }
+    // 파일이 깨졌으면 ports.json.bak 으로 복구, 구버전 스키마면 마이그레이션
+    Ok(schema::read_ports(&ports_file)?.unwrap_or_default())
+}
 
-    Ok(Vec::new())
+/// `save_ports` 응답
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SavedPorts {
+    /// 저장된 (포트가 채워진) 목록
+    ports: Vec<PortInfo>,
+    /// strict 가 아니어서 그대로 저장된 검증 경고 / 에러
+    issues: Vec<validation::ValidationIssue>,
 }
 
+/// ports.json 저장
+///
+/// `auto_assign` 이면 포트가 비어 있는 실행 가능 항목에 카테고리 범위의 빈 포트를 채운다.
+/// `strict` 이면 검증 에러(중복 id/포트, 잘못된 URL)가 있을 때 저장하지 않고 이슈 목록을 JSON 에러로 반환.
+/// 아니면 저장하고 이슈 목록을 함께 반환.
 #[tauri::command]
-fn save_ports(app_handle: tauri::AppHandle, ports: Vec<PortInfo>) -> Result<(), String> {
+fn save_ports(
+    app_handle: tauri::AppHandle,
+    mut ports: Vec<PortInfo>,
+    auto_assign: Option<bool>,
+    strict: Option<bool>,
+) -> Result<SavedPorts, String> {
     // Tauri app data 디렉토리 사용
     let app_data_dir = app_handle.path().app_data_dir()
         .map_err(|e| e.to_string())?;
@@ -79,19 +157,114 @@ fn save_ports(app_handle: tauri::AppHandle, ports: Vec<PortInfo>) -> Result<(),
             .map_err(|e| format!("Failed to create app data directory: {}", e))?;
     }
 
+    if auto_assign.unwrap_or(false) {
+        assign_missing_ports(&app_handle, &mut ports)?;
+    }
+
+    let issues = validation::validate(&ports);
+    if strict.unwrap_or(false) && validation::has_errors(&issues) {
+        println!("[SavePorts] Refusing to save: {} validation issues", issues.len());
+        return Err(validation::to_error(&issues));
+    }
+    for issue in &issues {
+        println!("[SavePorts] {:?} {} ({}): {}", issue.severity, issue.code, issue.port_id, issue.message);
+    }
+
     let ports_file = app_data_dir.join("ports.json");
     println!("[SavePorts] Saving {} ports to: {:?}", ports.len(), ports_file);
 
-    let content = serde_json::to_string_pretty(&ports)
-        .map_err(|e| e.to_string())?;
+    history::record_baseline(&app_handle, &ports_file);
+    schema::write_ports(&ports_file, &ports)?;
 
-    fs::write(&ports_file, content)
-        .map_err(|e| e.to_string())?;
+    // 되돌리기용 스냅샷 — 실패해도 저장 자체는 성공
+    if let Err(e) = history::record(&app_handle, &ports) {
+        println!("[SavePorts] Failed to record history snapshot: {}", e);
+    }
 
     println!("[SavePorts] Successfully saved ports");
+    Ok(SavedPorts { ports, issues })
+}
+
+/// 포트가 없는 항목에 빈 포트 할당 — 실행할 커맨드가 없거나 .html 인 항목은 서버가 아니므로 제외
+fn assign_missing_ports(app_handle: &tauri::AppHandle, ports: &mut [PortInfo]) -> Result<(), String> {
+    let ranges = port_alloc::load_ranges(app_handle)?;
+    let mut reserved: Vec<u16> = ports.iter().filter_map(|p| p.port).collect();
+    for p in ports.iter_mut().filter(|p| p.port.is_none()) {
+        let command = p.terminal_command.as_deref().or(p.command_path.as_deref()).unwrap_or("");
+        if command.trim().is_empty() || command.to_lowercase().ends_with(".html") {
+            continue;
+        }
+        match port_alloc::find_free(&ranges, p.category.as_deref(), None, &reserved) {
+            Some(port) => {
+                println!("[SavePorts] Assigned port {} to {}", port, p.name);
+                p.port = Some(port);
+                reserved.push(port);
+            }
+            None => println!("[SavePorts] No free port left for {}", p.name),
+        }
+    }
     Ok(())
 }
 
+/// 빈 포트 찾기 — OS 에서 사용 중인 포트와 ports.json 에 등록된 포트를 모두 피함
+///
+/// `exclude_id`: 편집 중인 항목 (자기 자신의 포트는 예약으로 치지 않음)
+#[tauri::command]
+fn find_free_port(
+    app_handle: tauri::AppHandle,
+    category: Option<String>,
+    preferred: Option<u16>,
+    exclude_id: Option<String>,
+) -> Result<u16, String> {
+    let ranges = port_alloc::load_ranges(&app_handle)?;
+    let reserved: Vec<u16> = load_ports(app_handle)?
+        .iter()
+        .filter(|p| Some(&p.id) != exclude_id.as_ref())
+        .filter_map(|p| p.port)
+        .collect();
+    let range = ranges.range_for(category.as_deref());
+    port_alloc::find_free(&ranges, category.as_deref(), preferred, &reserved)
+        .ok_or_else(|| format!("No free port in range {}-{}", range.start, range.end))
+}
+
+/// ports.json 저장 이력 (최신순)
+#[tauri::command]
+fn list_ports_history(app_handle: tauri::AppHandle) -> Vec<history::Snapshot> {
+    history::list(&app_handle)
+}
+
+/// 스냅샷과 현재 ports.json 비교
+#[tauri::command]
+fn diff_ports_snapshot(app_handle: tauri::AppHandle, snapshot_id: String) -> Result<history::SnapshotDiff, String> {
+    let snapshot = history::load(&app_handle, &snapshot_id)?;
+    let current = load_ports(app_handle)?;
+    Ok(history::diff(&snapshot, &current))
+}
+
+/// 스냅샷으로 ports.json 복원 — 복원 자체도 새 스냅샷으로 남으므로 다시 되돌릴 수 있음
+#[tauri::command]
+fn restore_ports_snapshot(app_handle: tauri::AppHandle, snapshot_id: String) -> Result<SavedPorts, String> {
+    let snapshot = history::load(&app_handle, &snapshot_id)?;
+    println!("[History] Restoring snapshot {} ({} entries)", snapshot_id, snapshot.len());
+    save_ports(app_handle, snapshot, None, None)
+}
+
+/// 저장 전 검증 — 경고/에러 목록 (UI 에서 저장 전에 표시)
+#[tauri::command]
+fn validate_ports(ports: Vec<PortInfo>) -> Vec<validation::ValidationIssue> {
+    validation::validate(&ports)
+}
+
+#[tauri::command]
+fn load_port_ranges(app_handle: tauri::AppHandle) -> Result<port_alloc::PortRanges, String> {
+    port_alloc::load_ranges(&app_handle)
+}
+
+#[tauri::command]
+fn save_port_ranges(app_handle: tauri::AppHandle, ranges: port_alloc::PortRanges) -> Result<(), String> {
+    port_alloc::save_ranges(&app_handle, &ranges)
+}
+
 #[tauri::command]
 fn scan_command_files(folder_path: String) -> Result<Vec<String>, String> {
     let path = std::path::Path::new(&folder_path);
@@ -126,57 +299,55 @@ fn open_app_data_dir(app_handle: tauri::AppHandle) -> Result<(), String> {
 }
 
 #[tauri::command]
-fn load_portal(app_handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
+fn load_portal(app_handle: tauri::AppHandle) -> Result<stores::PortalData, String> {
     let app_data_dir = app_handle.path().app_data_dir().map_err(|e| e.to_string())?;
     if !app_data_dir.exists() {
         fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
     }
     let file = app_data_dir.join("portal.json");
-    if file.exists() {
-        let content = fs::read_to_string(&file).map_err(|e| e.to_string())?;
-        let val: serde_json::Value = serde_json::from_str(&content).map_err(|e| e.to_string())?;
-        return Ok(val);
-    }
-    Ok(serde_json::json!({ "items": [], "categories": [] }))
+    // 깨진 항목은 건너뛰고 읽음
+    Ok(persist::read_json::<serde_json::Value>(&file)?
+        .map(stores::PortalData::from_value)
+        .unwrap_or_default())
 }
 
 #[tauri::command]
-fn save_portal(app_handle: tauri::AppHandle, data: serde_json::Value) -> Result<(), String> {
+fn save_portal(app_handle: tauri::AppHandle, mut data: stores::PortalData) -> Result<(), String> {
+    data.validate()?;
     let app_data_dir = app_handle.path().app_data_dir().map_err(|e| e.to_string())?;
     if !app_data_dir.exists() {
         fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
     }
     let file = app_data_dir.join("portal.json");
-    let content = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
-    fs::write(&file, content).map_err(|e| e.to_string())?;
-    Ok(())
+    // 파싱하지 못해 화면에 없던 항목은 디스크의 원본 그대로 유지
+    if let Ok(Some(existing)) = persist::read_json::<serde_json::Value>(&file) {
+        data.keep_unparsed(stores::PortalData::from_value(existing));
+    }
+    persist::write_json(&file, &data.to_value()?)
 }
 
 #[tauri::command]
-fn load_workspace_roots(app_handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
+fn load_workspace_roots(app_handle: tauri::AppHandle) -> Result<Vec<stores::WorkspaceRoot>, String> {
     let app_data_dir = app_handle.path().app_data_dir().map_err(|e| e.to_string())?;
     if !app_data_dir.exists() {
         fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
     }
     let file = app_data_dir.join("workspace-roots.json");
-    if file.exists() {
-        let content = fs::read_to_string(&file).map_err(|e| e.to_string())?;
-        let val: serde_json::Value = serde_json::from_str(&content).map_err(|e| e.to_string())?;
-        return Ok(val);
-    }
-    Ok(serde_json::Value::Array(vec![]))
+    Ok(persist::read_json::<serde_json::Value>(&file)?
+        .map(stores::workspace_roots_from_value)
+        .unwrap_or_default())
 }
 
 #[tauri::command]
-fn save_workspace_roots(app_handle: tauri::AppHandle, roots: serde_json::Value) -> Result<(), String> {
+fn save_workspace_roots(app_handle: tauri::AppHandle, roots: Vec<stores::WorkspaceRoot>) -> Result<(), String> {
+    stores::validate_workspace_roots(&roots)?;
     let app_data_dir = app_handle.path().app_data_dir().map_err(|e| e.to_string())?;
     if !app_data_dir.exists() {
         fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
     }
     let file = app_data_dir.join("workspace-roots.json");
-    let content = serde_json::to_string_pretty(&roots).map_err(|e| e.to_string())?;
-    fs::write(&file, content).map_err(|e| e.to_string())?;
-    Ok(())
+    let existing = persist::read_json::<serde_json::Value>(&file).ok().flatten();
+    persist::write_json(&file, &stores::workspace_roots_to_value(&roots, existing)?)
 }
 
 #[tauri::command]
@@ -194,14 +365,79 @@ fn create_folder(folder_path: String) -> Result<String, String> {
     Ok(folder_path)
 }
 
+/// ports.json 의 포트 번호 변경 (충돌 시 빈 포트로 옮길 때)
+fn update_port_number(app_handle: &tauri::AppHandle, port_id: &str, port: u16) -> Result<(), String> {
+    let mut ports = load_ports(app_handle.clone())?;
+    let entry = ports.iter_mut()
+        .find(|p| p.id == port_id)
+        .ok_or_else(|| format!("Unknown port id: {}", port_id))?;
+    entry.port = Some(port);
+    save_ports(app_handle.clone(), ports, None, None).map(|_| ())
+}
+
+/// `waitReady` 기본 대기 시간
+const DEFAULT_READY_TIMEOUT_MS: u64 = 30_000;
+
+/// `execute_command` / `force_restart_command` 의 선택 인자 (`options`)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LaunchOptions {
+    /// 포트가 요청을 받을 때까지 대기 후 반환
+    #[serde(default)]
+    wait_ready: bool,
+    ready_timeout_ms: Option<u64>,
+    /// 포트 충돌 시 처리 — `execute_command` 만, 없으면 그대로 실행
+    on_conflict: Option<conflict::ConflictAction>,
+}
+
+/// 서버가 요청을 받을 수 있을 때까지 대기 (`waitReady`) — 실패하면 로그 마지막 부분을 에러에 포함
+///
+/// 대기할 포트가 없으면 바로 `Ok(None)`.
+async fn wait_until_ready(
+    port_info: Option<PortInfo>,
+    port: Option<u16>,
+    pid: u32,
+    log_file: std::path::PathBuf,
+    timeout_ms: Option<u64>,
+) -> Result<Option<std::time::Duration>, String> {
+    let Some(port) = port.or_else(|| port_info.as_ref().and_then(|p| p.port)) else {
+        println!("[WaitReady] No port configured, skipping readiness wait");
+        return Ok(None);
+    };
+    let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_READY_TIMEOUT_MS));
+    let health_check = port_info.and_then(|p| p.health_check);
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        health::wait_ready(port, health_check.as_ref(), pid, timeout)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    match result {
+        Ok(elapsed) => {
+            println!("[WaitReady] Port {} ready after {:?}", port, elapsed);
+            Ok(Some(elapsed))
+        }
+        Err(e) => {
+            let tail = logs::tail(&log_file, 40);
+            if tail.is_empty() {
+                Err(e)
+            } else {
+                Err(format!("{}\n--- last log lines ---\n{}", e, tail))
+            }
+        }
+    }
+}
+
 #[tauri::command]
-fn execute_command(
+async fn execute_command(
     port_id: String,
     command_path: String,
     folder_path: Option<String>,
-    state: State<AppState>,
+    options: Option<LaunchOptions>,
     app_handle: tauri::AppHandle,
 ) -> Result<String, String> {
+    let options = options.unwrap_or_default();
     // 파일 경로인지 raw 커맨드인지 판별 (절대경로 = 파일, 아니면 shell 커맨드)
     let is_file_path = command_path.starts_with('/') || command_path.starts_with('~');
     let command_path_buf = std::path::PathBuf::from(&command_path);
@@ -250,220 +486,168 @@ fn execute_command(
     let log_file = logs_dir.join(format!("{}.log", port_id));
     println!("[ExecuteCommand] Log file: {:?}", log_file);
 
-    // 로그 파일 열기 (append 모드)
-    let log_out = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
-
-    let log_err = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
+    // 소켓 스캔 / 점유 프로세스 종료 / spawn 은 블로킹 — 런타임 스레드 밖에서
+    let app = app_handle.clone();
+    let spec = supervisor::LaunchSpec {
+        port_id: port_id.clone(),
+        command_path,
+        folder_path,
+        log_file: log_file.clone(),
+        port: None,
+    };
+    let on_conflict = options.on_conflict;
+    let (pid, port_info) = tauri::async_runtime::spawn_blocking(move || launch_checked(&app, spec, on_conflict))
+        .await
+        .map_err(|e| e.to_string())??;
 
-    // .command 파일에 실행 권한 부여 (파일 경로인 경우만)
-    if is_file_path {
-        let chmod_result = Command::new("chmod")
-            .arg("+x")
-            .arg(&command_path)
-            .output();
+    println!("[ExecuteCommand] Started process with PID: {}", pid);
 
-        match chmod_result {
-            Ok(out) => {
-                if out.status.success() {
-                    println!("[ExecuteCommand] Successfully set execute permission");
-                } else {
-                    println!("[ExecuteCommand] Warning: chmod failed: {}", String::from_utf8_lossy(&out.stderr));
-                }
-            }
-            Err(e) => {
-                println!("[ExecuteCommand] Warning: chmod error: {}", e);
-            }
+    if options.wait_ready {
+        if let Some(elapsed) = wait_until_ready(port_info, None, pid, log_file.clone(), options.ready_timeout_ms).await? {
+            return Ok(format!("Started process with PID: {} (logs: {:?}), ready after {:?}", pid, log_file, elapsed));
         }
     }
 
-    // 환경변수 설정 (GUI 앱에서 터미널 환경변수 상속)
-    let home = std::env::var("HOME").unwrap_or_default();
-
-    // PATH 환경변수에 일반적인 경로들 추가
-    let path_additions = vec![
-        format!("{}/.cargo/bin", home),
-        format!("{}/.bun/bin", home),
-        format!("{}/bin", home),
-        "/usr/local/bin".to_string(),
-        "/usr/bin".to_string(),
-        "/bin".to_string(),
-        "/usr/sbin".to_string(),
-        "/sbin".to_string(),
-        "/opt/homebrew/bin".to_string(),
-        "/usr/local/go/bin".to_string(),
-    ];
-
-    let existing_path = std::env::var("PATH").unwrap_or_default();
-    let new_path = if existing_path.is_empty() {
-        path_additions.join(":")
-    } else {
-        format!("{}:{}", path_additions.join(":"), existing_path)
-    };
-
-    // 프로세스 실행 시 stdout, stderr를 로그 파일로 리다이렉트
-    // setsid를 사용하여 새로운 세션으로 실행 (백그라운드 프로세스)
-    if is_file_path {
-        println!("[ExecuteCommand] Executing: bash {}", command_path);
-    } else {
-        println!("[ExecuteCommand] Executing: bash -c {}", command_path);
-    }
-    println!("[ExecuteCommand] PATH: {}", new_path);
+    Ok(format!("Started process with PID: {} (logs: {:?})", pid, log_file))
+}
 
-    let mut cmd = Command::new("bash");
-    if is_file_path {
-        cmd.arg(&command_path);
-    } else {
-        cmd.arg("-c").arg(&command_path);
-    }
-    // raw 커맨드(terminalCommand)는 folderPath를 cwd로 설정
-    if !is_file_path {
-        if let Some(ref fp) = folder_path {
-            if !fp.is_empty() {
-                cmd.current_dir(fp);
-            }
-        }
-    }
-    cmd
-        .stdout(log_out)
-        .stderr(log_err)
-        .env("PATH", &new_path)
-        .env("HOME", &home);
+/// 포트 충돌 확인(`on_conflict` 처리) 후 실행 — 실행한 PID 와 (포트가 바뀌었으면 반영된) PortInfo 반환
+fn launch_checked(
+    app_handle: &tauri::AppHandle,
+    mut spec: supervisor::LaunchSpec,
+    on_conflict: Option<conflict::ConflictAction>,
+) -> Result<(u32, Option<PortInfo>), String> {
+    let port_id = spec.port_id.clone();
 
-    // 새로운 프로세스 그룹으로 실행 (백그라운드 데몬화) — Unix 전용
-    #[cfg(unix)]
+    // 포트 충돌 사전 확인 — 이미 점유돼 있으면 EADDRINUSE 로 죽기 전에 알림
+    let mut port_info = find_port_info(app_handle, &port_id);
+    if let Some(conflict) = port_info.as_ref()
+        .and_then(|p| p.port)
+        .and_then(|port| conflict::detect(app_handle, &port_id, port))
     {
-        use std::os::unix::process::CommandExt;
-        unsafe {
-            cmd.pre_exec(|| {
-                // 새로운 세션 리더가 되어 부모와 독립적으로 실행
-                libc::setsid();
-                Ok(())
-            });
+        println!("[ExecuteCommand] Port {} is in use by {:?}", conflict.port, conflict.owners.iter().map(|o| o.pid).collect::<Vec<_>>());
+        match on_conflict {
+            // onConflict 를 모르는 호출자 — 이전과 같이 실행 (실패하면 로그에 EADDRINUSE)
+            None => println!("[ExecuteCommand] No onConflict given, launching anyway"),
+            Some(conflict::ConflictAction::Cancel) => return Err(conflict.to_error()),
+            Some(conflict::ConflictAction::Kill) => conflict::kill_owners(app_handle, &conflict),
+            Some(conflict::ConflictAction::FreePort) => {
+                let Some(free_port) = conflict.suggested_port else { return Err(conflict.to_error()) };
+                println!("[ExecuteCommand] Using free port {} instead of {}", free_port, conflict.port);
+                update_port_number(app_handle, &port_id, free_port)?;
+                if let Some(p) = port_info.as_mut() {
+                    p.port = Some(free_port);
+                }
+                spec.port = Some(free_port);
+            }
         }
     }
 
-    let child = cmd.spawn()
-        .map_err(|e| format!("Failed to spawn process: {}", e))?;
-
-    let pid = child.id();
-
-    let mut processes = state.processes.lock().unwrap();
-    processes.insert(port_id.clone(), pid);
-
-    println!("[ExecuteCommand] Started process with PID: {}", pid);
-
-    Ok(format!("Started process with PID: {} (logs: {:?})", pid, log_file))
+    let policy = port_info.as_ref()
+        .and_then(|p| p.restart_policy.clone())
+        .unwrap_or_default();
+    let state = app_handle.state::<AppState>();
+    let pid = state.supervisor.launch(app_handle, spec, policy, "[ExecuteCommand]")?;
+    Ok((pid, port_info))
 }
 
 #[tauri::command]
-fn stop_command(
+async fn stop_command(
     port_id: String,
     port: u16,
-    state: State<AppState>,
+    app_handle: tauri::AppHandle,
 ) -> Result<String, String> {
     println!("[StopCommand] Starting stop for port_id: {}, port: {}", port_id, port);
 
-    let mut processes = state.processes.lock().unwrap();
+    // stopCommand (최대 STOP_COMMAND_TIMEOUT) + 세션별 grace 대기 — 런타임 스레드 밖에서
+    let app = app_handle.clone();
+    let (pid_from_map, killed_pids) = tauri::async_runtime::spawn_blocking(move || stop_project(&app, &port_id, port))
+        .await
+        .map_err(|e| e.to_string())?;
 
-    // HashMap에서 PID 제거
-    let pid_from_map = processes.remove(&port_id);
-    drop(processes); // lock 해제
+    if killed_pids.is_empty() {
+        if pid_from_map.is_some() {
+            println!("[StopCommand] Process from map was removed but not found on port");
+            Ok(format!("Process stopped (was in tracking map)"))
+        } else {
+            println!("[StopCommand] No process found on port {} (already stopped)", port);
+            Ok(format!("No process running on port {} (already stopped)", port))
+        }
+    } else {
+        println!("[StopCommand] Successfully stopped {} process(es): {:?}", killed_pids.len(), killed_pids);
+        Ok(format!("Stopped {} process(es) with PIDs: {:?}", killed_pids.len(), killed_pids))
+    }
+}
+
+/// 프로젝트 서버 종료 — `stopCommand` → 앱이 띄운 세션 전체 → 남은 포트 소유 프로세스 순
+///
+/// `stopSignal` / `stopTimeoutMs` 를 따른다. 추적 맵에 있던 PID 와 종료한 PID 목록 반환.
+fn stop_project(app_handle: &tauri::AppHandle, port_id: &str, port: u16) -> (Option<u32>, Vec<u32>) {
+    // 감시 스레드가 이번 종료를 크래시로 보고 재시작하지 않도록 먼저 표시
+    app_handle.state::<AppState>().supervisor.mark_stopping(port_id);
+
+    let port_info = find_port_info(app_handle, port_id);
+
+    // 추적 맵(+ processes.json)에서 PID 제거
+    let pid_from_map = tracked::remove(app_handle, port_id);
 
-    // 포트로 실행 중인 모든 프로세스 찾기
+    // 포트로 실행 중인 모든 프로세스 찾기 (네이티브 소켓 조회 — lsof 불필요)
+    // 앱 외부에서 띄웠거나 watcher 가 재시작한 서버도 포함
     let mut killed_pids = Vec::new();
 
-    #[cfg(target_os = "macos")]
+    #[cfg(unix)]
     {
-        let output = Command::new("lsof")
-            .arg("-ti")
-            .arg(format!(":{}", port))
-            .output();
+        let stop_signal = port_info.as_ref().and_then(|p| p.stop_signal).unwrap_or_default();
+        let grace = port_info.as_ref()
+            .and_then(|p| p.stop_timeout_ms)
+            .map(std::time::Duration::from_millis)
+            .unwrap_or(shutdown::DEFAULT_GRACE);
+
+        // 사용자 정의 종료 커맨드 먼저 — 실패/타임아웃이어도 아래 시그널 단계로 마무리
+        if let Some(stop_cmd) = port_info.as_ref().and_then(|p| p.stop_command.as_deref()).filter(|c| !c.trim().is_empty()) {
+            let log_file = app_handle.path().app_data_dir().ok()
+                .map(|d| d.join("logs").join(format!("{}.log", port_id)));
+            match shutdown::run_stop_command(
+                stop_cmd,
+                port_info.as_ref().and_then(|p| p.folder_path.as_deref()),
+                log_file.as_deref(),
+                shutdown::STOP_COMMAND_TIMEOUT,
+            ) {
+                Ok(()) => println!("[StopCommand] Stop command finished"),
+                Err(e) => println!("[StopCommand] {}", e),
+            }
+        }
 
-        match output {
-            Ok(out) => {
-                if out.status.success() && !out.stdout.is_empty() {
-                    let pid_str = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                    let pids: Vec<&str> = pid_str.lines().collect();
-
-                    if !pids.is_empty() {
-                        println!("[StopCommand] Found {} PIDs on port {}: {:?}", pids.len(), port, pids);
-
-                        // 모든 PID 종료
-                        for pid_str in pids {
-                            if let Ok(pid) = pid_str.parse::<u32>() {
-                                println!("[StopCommand] Killing PID: {}", pid);
-
-                                // 먼저 SIGTERM 시도
-                                let term_result = Command::new("kill")
-                                    .arg("-15")
-                                    .arg(pid.to_string())
-                                    .output();
-
-                                match term_result {
-                                    Ok(output) => {
-                                        if output.status.success() {
-                                            println!("[StopCommand] SIGTERM sent to PID: {}", pid);
-                                            // 잠시 대기
-                                            std::thread::sleep(std::time::Duration::from_millis(200));
-
-                                            // 프로세스가 아직 살아있는지 확인
-                                            let check = Command::new("kill")
-                                                .arg("-0")
-                                                .arg(pid.to_string())
-                                                .output();
-
-                                            if check.is_ok() && check.unwrap().status.success() {
-                                                // 여전히 살아있으면 SIGKILL
-                                                println!("[StopCommand] Process still alive, sending SIGKILL to PID: {}", pid);
-                                                let _ = Command::new("kill")
-                                                    .arg("-9")
-                                                    .arg(pid.to_string())
-                                                    .output();
-                                            }
-                                        } else {
-                                            // SIGTERM 실패하면 바로 SIGKILL
-                                            println!("[StopCommand] SIGTERM failed, sending SIGKILL to PID: {}", pid);
-                                            let _ = Command::new("kill")
-                                                .arg("-9")
-                                                .arg(pid.to_string())
-                                                .output();
-                                        }
-                                        killed_pids.push(pid);
-                                    }
-                                    Err(e) => {
-                                        println!("[StopCommand] Error sending SIGTERM to PID {}: {}", pid, e);
-                                        // 에러 나도 SIGKILL 시도
-                                        let _ = Command::new("kill")
-                                            .arg("-9")
-                                            .arg(pid.to_string())
-                                            .output();
-                                        killed_pids.push(pid);
-                                    }
-                                }
-                            }
-                        }
-                    } else {
-                        println!("[StopCommand] No processes found on port {}", port);
-                    }
-                } else {
-                    println!("[StopCommand] No processes found on port {} (lsof returned empty)", port);
+        let port_pids = sockets::listening_pids(port);
+        if port_pids.is_empty() {
+            println!("[StopCommand] No processes found on port {}", port);
+        } else {
+            println!("[StopCommand] Found {} PIDs on port {}: {:?}", port_pids.len(), port, port_pids);
+        }
+
+        // 앱이 띄운 세션 전체 종료 (bash 세션 리더 + bundler watcher / esbuild / tsc --watch 등 자식)
+        let launched: Vec<u32> = pid_from_map
+            .into_iter()
+            .chain(app_handle.state::<AppState>().supervisor.snapshot(port_id).map(|s| s.pid))
+            .collect();
+        for sid in shutdown::owned_sessions(&launched, &port_pids) {
+            for pid in shutdown::terminate_session(sid, stop_signal, grace) {
+                if !killed_pids.contains(&pid) {
+                    killed_pids.push(pid);
                 }
             }
-            Err(e) => {
-                println!("[StopCommand] Error running lsof: {}", e);
+        }
+
+        // 앱 외부에서 띄운 서버 — 남의 세션(터미널 등)은 건드리지 않고 포트 소유 프로세스만 종료
+        for pid in port_pids {
+            if !killed_pids.contains(&pid) && procinfo::is_alive(pid) {
+                shutdown::terminate_pid(pid, stop_signal, grace);
+                killed_pids.push(pid);
             }
         }
     }
 
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(not(unix))]
     {
         if let Some(pid) = pid_from_map {
             let _ = Command::new("kill")
@@ -474,30 +658,24 @@ fn stop_command(
         }
     }
 
-    if killed_pids.is_empty() {
-        if pid_from_map.is_some() {
-            println!("[StopCommand] Process from map was removed but not found on port");
-            Ok(format!("Process stopped (was in tracking map)"))
-        } else {
-            println!("[StopCommand] No process found on port {} (already stopped)", port);
-            Ok(format!("No process running on port {} (already stopped)", port))
-        }
-    } else {
-        println!("[StopCommand] Successfully stopped {} process(es): {:?}", killed_pids.len(), killed_pids);
-        Ok(format!("Stopped {} process(es) with PIDs: {:?}", killed_pids.len(), killed_pids))
-    }
+    (pid_from_map, killed_pids)
 }
 
 #[tauri::command]
-fn force_restart_command(
+async fn force_restart_command(
     port_id: String,
     port: u16,
     command_path: String,
-    state: State<AppState>,
+    folder_path: Option<String>,
+    options: Option<LaunchOptions>,
     app_handle: tauri::AppHandle,
 ) -> Result<String, String> {
+    let options = options.unwrap_or_default();
     println!("[ForceRestart] Starting force restart for port_id: {}, port: {}", port_id, port);
 
+    // 기존 감시 스레드가 강제 종료를 크래시로 보고 재시작하지 않도록 표시
+    app_handle.state::<AppState>().supervisor.mark_stopping(&port_id);
+
     // .html 파일은 기본 브라우저로 열기
     if command_path.to_lowercase().ends_with(".html") {
         #[cfg(target_os = "macos")]
@@ -518,42 +696,63 @@ fn force_restart_command(
         return Ok("Opened HTML file in browser".to_string());
     }
 
-    // 1단계: 포트로 실행 중인 모든 프로세스 강제 종료
-    #[cfg(target_os = "macos")]
-    {
-        let output = Command::new("lsof")
-            .arg("-ti")
-            .arg(format!(":{}", port))
-            .output();
+    // 종료 대기 / spawn 은 블로킹 — 런타임 스레드 밖에서
+    let app = app_handle.clone();
+    let (new_pid, log_file, port_info) = tauri::async_runtime::spawn_blocking(move || {
+        force_relaunch(&app, port_id, port, command_path, folder_path)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
 
-        match output {
-            Ok(out) => {
-                if out.status.success() && !out.stdout.is_empty() {
-                    let pid_str = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                    let pids: Vec<&str> = pid_str.lines().collect();
-
-                    for pid_str in pids {
-                        if let Ok(pid) = pid_str.parse::<u32>() {
-                            println!("[ForceRestart] Force killing PID: {}", pid);
-                            // SIGKILL로 즉시 강제 종료
-                            let _ = Command::new("kill")
-                                .arg("-9")
-                                .arg(pid.to_string())
-                                .output();
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                println!("[ForceRestart] Error running lsof: {}", e);
+    println!("[ForceRestart] Successfully restarted with new PID: {}", new_pid);
+
+    if options.wait_ready {
+        if let Some(elapsed) = wait_until_ready(port_info, Some(port), new_pid, log_file, options.ready_timeout_ms).await? {
+            return Ok(format!("Force restarted on port {} with new PID: {}, ready after {:?}", port, new_pid, elapsed));
+        }
+    }
+
+    Ok(format!("Force restarted on port {} with new PID: {}", port, new_pid))
+}
+
+/// 포트 / 세션의 프로세스를 모두 강제 종료한 뒤 다시 실행 — 새 PID, 로그 파일, PortInfo 반환
+fn force_relaunch(
+    app_handle: &tauri::AppHandle,
+    port_id: String,
+    port: u16,
+    command_path: String,
+    folder_path: Option<String>,
+) -> Result<(u32, std::path::PathBuf, Option<PortInfo>), String> {
+    let state = app_handle.state::<AppState>();
+    // 1단계: 앱이 띄운 세션 전체 + 포트로 실행 중인 모든 프로세스 강제 종료
+    #[cfg(unix)]
+    {
+        let port_pids = sockets::listening_pids(port);
+        let launched: Vec<u32> = state.processes.lock().unwrap()
+            .get(&port_id)
+            .copied()
+            .into_iter()
+            .chain(state.supervisor.snapshot(&port_id).map(|s| s.pid))
+            .collect();
+        let mut killed = Vec::new();
+        for sid in shutdown::owned_sessions(&launched, &port_pids) {
+            killed.extend(shutdown::kill_session(sid));
+        }
+        for pid in port_pids {
+            if !killed.contains(&pid) {
+                // SIGKILL로 즉시 강제 종료
+                let _ = Command::new("kill")
+                    .arg("-9")
+                    .arg(pid.to_string())
+                    .output();
+                killed.push(pid);
             }
         }
+        println!("[ForceRestart] Force killed PIDs: {:?}", killed);
     }
 
-    // HashMap에서도 제거
-    let mut processes = state.processes.lock().unwrap();
-    processes.remove(&port_id);
-    drop(processes); // lock 해제
+    // 추적 맵(+ processes.json)에서도 제거
+    tracked::remove(app_handle, &port_id);
 
     // 잠시 대기 (프로세스가 완전히 종료될 시간)
     std::thread::sleep(std::time::Duration::from_millis(500));
@@ -585,149 +784,123 @@ fn force_restart_command(
     let log_file = logs_dir.join(format!("{}.log", port_id));
     println!("[ForceRestart] Log file: {:?}", log_file);
 
-    // 로그 파일 열기 (append 모드)
-    let log_out = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
-
-    let log_err = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
-
-    // .command 파일에 실행 권한 부여
-    let chmod_result = Command::new("chmod")
-        .arg("+x")
-        .arg(&command_path)
-        .output();
-
-    match chmod_result {
-        Ok(out) => {
-            if out.status.success() {
-                println!("[ForceRestart] Successfully set execute permission");
-            } else {
-                println!("[ForceRestart] Warning: chmod failed: {}", String::from_utf8_lossy(&out.stderr));
-            }
-        }
-        Err(e) => {
-            println!("[ForceRestart] Warning: chmod error: {}", e);
-        }
-    }
-
-    // 환경변수 설정 (GUI 앱에서 터미널 환경변수 상속)
-    let home = std::env::var("HOME").unwrap_or_default();
-
-    // PATH 환경변수에 일반적인 경로들 추가
-    let path_additions = vec![
-        format!("{}/.cargo/bin", home),
-        format!("{}/.bun/bin", home),
-        format!("{}/bin", home),
-        "/usr/local/bin".to_string(),
-        "/usr/bin".to_string(),
-        "/bin".to_string(),
-        "/usr/sbin".to_string(),
-        "/sbin".to_string(),
-        "/opt/homebrew/bin".to_string(),
-        "/usr/local/go/bin".to_string(),
-    ];
-
-    let existing_path = std::env::var("PATH").unwrap_or_default();
-    let new_path = if existing_path.is_empty() {
-        path_additions.join(":")
-    } else {
-        format!("{}:{}", path_additions.join(":"), existing_path)
+    let spec = supervisor::LaunchSpec {
+        port_id: port_id.clone(),
+        command_path,
+        folder_path,
+        log_file: log_file.clone(),
+        port: None,
     };
+    let port_info = find_port_info(app_handle, &port_id);
+    let policy = port_info.as_ref()
+        .and_then(|p| p.restart_policy.clone())
+        .unwrap_or_default();
 
-    // 프로세스 실행 시 stdout, stderr를 로그 파일로 리다이렉트
-    // setsid를 사용하여 새로운 세션으로 실행 (백그라운드 프로세스)
-    if is_file_path {
-        println!("[ForceRestart] Executing: bash {}", command_path);
-    } else {
-        println!("[ForceRestart] Executing: bash -c {}", command_path);
-    }
-    println!("[ForceRestart] PATH: {}", new_path);
-
-    let mut cmd = Command::new("bash");
-    if is_file_path {
-        cmd.arg(&command_path);
-    } else {
-        cmd.arg("-c").arg(&command_path);
-    }
-    cmd
-        .stdout(log_out)
-        .stderr(log_err)
-        .env("PATH", &new_path)
-        .env("HOME", &home);
-
-    // 새로운 프로세스 그룹으로 실행 (백그라운드 데몬화) — Unix 전용
-    #[cfg(unix)]
-    {
-        use std::os::unix::process::CommandExt;
-        unsafe {
-            cmd.pre_exec(|| {
-                // 새로운 세션 리더가 되어 부모와 독립적으로 실행
-                libc::setsid();
-                Ok(())
-            });
-        }
-    }
+    let new_pid = state.supervisor.launch(app_handle, spec, policy, "[ForceRestart]")?;
+    Ok((new_pid, log_file, port_info))
+}
 
-    let child = cmd.spawn()
-        .map_err(|e| format!("Failed to spawn process: {}", e))?;
+#[tauri::command]
+fn check_port_status(port: u16) -> Result<bool, String> {
+    let is_running = !sockets::listeners(port).is_empty();
+    println!("[CheckPort] Port {} is {}", port, if is_running { "RUNNING" } else { "NOT running" });
+    Ok(is_running)
+}
 
-    let new_pid = child.id();
+/// ports.json 에 등록된 모든 포트의 리스너를 한 번의 소켓 스캔으로 조회
+#[tauri::command]
+fn get_port_listeners(app_handle: tauri::AppHandle) -> Result<HashMap<u16, Vec<sockets::Listener>>, String> {
+    let ports: Vec<u16> = load_ports(app_handle)?
+        .iter()
+        .filter_map(|p| p.port)
+        .collect();
+    Ok(sockets::listeners_by_port(&ports))
+}
 
-    let mut processes = state.processes.lock().unwrap();
-    processes.insert(port_id.clone(), new_pid);
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct PortStatus {
+    listening: bool,
+    pids: Vec<u32>,
+    process_name: Option<String>,
+    bind_address: Option<String>,
+    /// 포트를 점유한 가장 오래된 프로세스 기준 가동 시간 (초)
+    uptime_secs: Option<u64>,
+}
 
-    println!("[ForceRestart] Successfully restarted with new PID: {}", new_pid);
+/// 대시보드 새로고침용 — 여러 포트의 상태를 한 번의 IPC / 한 번의 소켓 스캔으로 반환
+#[tauri::command]
+fn check_ports_status(ports: Vec<u16>) -> Result<HashMap<u16, PortStatus>, String> {
+    let now = procinfo::now_secs();
+    let statuses = sockets::listeners_by_port(&ports)
+        .into_iter()
+        .map(|(port, listeners)| {
+            let pids = sockets::unique_pids(&listeners);
+            let oldest_start = pids.iter().filter_map(|&pid| procinfo::process_start_time(pid)).min();
+            let status = PortStatus {
+                listening: !listeners.is_empty(),
+                process_name: pids.first().and_then(|&pid| procinfo::process_name(pid)),
+                bind_address: listeners.first().map(|l| l.address.clone()),
+                uptime_secs: oldest_start.map(|start| now.saturating_sub(start)),
+                pids,
+            };
+            (port, status)
+        })
+        .collect();
+    Ok(statuses)
+}
 
-    Ok(format!("Force restarted on port {} with new PID: {}", port, new_pid))
+/// 실행 전 포트 충돌 확인 — 점유 프로세스가 없으면 None
+#[tauri::command]
+fn check_port_conflict(app_handle: tauri::AppHandle, port_id: String, port: Option<u16>) -> Result<Option<conflict::PortConflict>, String> {
+    let Some(port) = port.or_else(|| find_port_info(&app_handle, &port_id).and_then(|p| p.port)) else {
+        return Ok(None);
+    };
+    Ok(conflict::detect(&app_handle, &port_id, port))
 }
 
+/// Supervisor 가 관리 중인 모든 프로세스의 마지막 상태
 #[tauri::command]
-fn check_port_status(port: u16) -> Result<bool, String> {
-    #[cfg(target_os = "macos")]
-    {
-        let output = Command::new("lsof")
-            .arg("-ti")
-            .arg(format!(":{}", port))
-            .output();
+fn get_process_states(state: State<AppState>) -> Vec<supervisor::ProcessSnapshot> {
+    state.supervisor.snapshots()
+}
 
-        match output {
-            Ok(out) => {
-                let is_running = out.status.success() && !out.stdout.is_empty();
-                println!("[CheckPort] Port {} is {}", port, if is_running { "RUNNING" } else { "NOT running" });
-                Ok(is_running)
-            }
-            Err(e) => {
-                println!("[CheckPort] Error checking port {}: {}", port, e);
-                Ok(false) // 에러가 나면 실행 중이 아닌 것으로 간주
-            }
-        }
-    }
+/// 백그라운드 체크 스레드의 마지막 헬스 체크 결과
+#[tauri::command]
+fn get_health_status(state: State<AppState>) -> Vec<health::HealthReport> {
+    state.health.reports()
+}
 
-    #[cfg(not(target_os = "macos"))]
-    {
-        Ok(false)
-    }
+/// 헬스 프로브 즉시 실행 (결과는 캐시에도 반영되어 상태가 바뀌면 `health-changed` 이벤트 발생)
+#[tauri::command]
+async fn check_health(app_handle: tauri::AppHandle, port_id: String) -> Result<health::HealthReport, String> {
+    let port_info = find_port_info(&app_handle, &port_id)
+        .ok_or_else(|| format!("Unknown port id: {}", port_id))?;
+    let health_check = port_info.health_check.clone()
+        .ok_or_else(|| format!("No health check configured for {}", port_info.name))?;
+    let report = tauri::async_runtime::spawn_blocking(move || health::check(&port_info, &health_check))
+        .await
+        .map_err(|e| e.to_string())?;
+    app_handle.state::<AppState>().health.record(&app_handle, report.clone());
+    Ok(report)
 }
 
 #[tauri::command]
 fn detect_port(file_path: String) -> Result<Option<u16>, String> {
     let content = fs::read_to_string(&file_path)
         .map_err(|e| e.to_string())?;
+    Ok(detect_port_in(&content))
+}
 
+/// 스크립트 / 커맨드 문자열에서 포트 추정
+fn detect_port_in(content: &str) -> Option<u16> {
     // localhost:포트 패턴 검색
     if let Some(caps) = regex::Regex::new(r"localhost:(\d+)")
         .unwrap()
-        .captures(&content) {
+        .captures(content) {
         if let Some(port_str) = caps.get(1) {
             if let Ok(port) = port_str.as_str().parse::<u16>() {
-                return Ok(Some(port));
+                return Some(port);
             }
         }
     }
@@ -735,15 +908,26 @@ fn detect_port(file_path: String) -> Result<Option<u16>, String> {
     // PORT=포트 또는 port=포트 패턴 검색
     if let Some(caps) = regex::Regex::new(r"(?:PORT|port)\s*=\s*(\d+)")
         .unwrap()
-        .captures(&content) {
+        .captures(content) {
+        if let Some(port_str) = caps.get(1) {
+            if let Ok(port) = port_str.as_str().parse::<u16>() {
+                return Some(port);
+            }
+        }
+    }
+
+    // --port 3000 / -p 3000 (next dev, vite 등 CLI 인자)
+    if let Some(caps) = regex::Regex::new(r"(?:--port[=\s]+|\s-p\s+)(\d+)")
+        .unwrap()
+        .captures(content) {
         if let Some(port_str) = caps.get(1) {
             if let Ok(port) = port_str.as_str().parse::<u16>() {
-                return Ok(Some(port));
+                return Some(port);
             }
         }
     }
 
-    Ok(None)
+    None
 }
 
 #[tauri::command]
@@ -956,40 +1140,133 @@ fn open_log(port_id: String, app_handle: tauri::AppHandle) -> Result<String, Str
     Ok(format!("로그 파일을 열었습니다: {:?}", log_file))
 }
 
+/// 현재 로그를 즉시 `.log.1` 로 보관하고 비움 (보관 파일 경로 반환, 로그가 비어 있으면 None)
 #[tauri::command]
-fn read_log_content(port_id: String, offset: usize, app_handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
-    let app_data_dir = app_handle.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
-
-    let logs_dir = app_data_dir.join("logs");
-    let log_file = logs_dir.join(format!("{}.log", port_id));
+fn archive_log(app_handle: tauri::AppHandle, port_id: String) -> Result<Option<String>, String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    let retention = find_port_info(&app_handle, &port_id)
+        .and_then(|p| p.log_retention)
+        .unwrap_or_default();
+    logs::rotate(&capture::stamped_path(&log_file), &retention)?;
+    Ok(logs::rotate(&log_file, &retention)?.map(|p| p.to_string_lossy().to_string()))
+}
 
-    if !log_file.exists() {
-        return Ok(serde_json::json!({
-            "content": "",
-            "size": 0,
-            "exists": false
-        }));
+/// 로그 비우기 — `include_archives` 면 `.log.1`, `.log.2.gz` … 도 삭제
+#[tauri::command]
+fn clear_logs(app_handle: tauri::AppHandle, port_id: String, include_archives: Option<bool>) -> Result<(), String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    let include_archives = include_archives.unwrap_or(false);
+    logs::clear(&log_file, include_archives)?;
+    logs::clear(&capture::stamped_path(&log_file), include_archives)?;
+    if include_archives {
+        runs::clear(&log_file);
     }
+    Ok(())
+}
 
-    let content = fs::read_to_string(&log_file)
-        .map_err(|e| format!("Failed to read log file: {}", e))?;
+/// 실행(run) 목록 — 최신순, 각 실행의 커맨드 / PID / 종료 코드 / 실행 시간
+#[tauri::command]
+fn list_log_runs(app_handle: tauri::AppHandle, port_id: String) -> Result<Vec<runs::RunRecord>, String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    Ok(runs::list(&log_file))
+}
 
-    let size = content.len();
-    // Find safe UTF-8 char boundary at or after offset to avoid panic on multi-byte chars
-    let safe_offset = if offset > 0 && offset < size {
-        (offset..=size).find(|&i| content.is_char_boundary(i)).unwrap_or(size)
-    } else {
-        0
+/// 특정 실행의 로그 (헤더 ~ 종료 푸터, rotation 된 보관 로그 포함)
+#[tauri::command]
+async fn read_log_run(app_handle: tauri::AppHandle, port_id: String, run: u64, max_bytes: Option<usize>) -> Result<runs::RunLog, String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    tauri::async_runtime::spawn_blocking(move || runs::read(&log_file, run, max_bytes.unwrap_or(runs::DEFAULT_RUN_BYTES)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// `offset` 이후 새 로그만 읽기 (파일 전체를 다시 읽지 않음)
+///
+/// 다음 호출에는 `nextOffset` 과 `fingerprint` 를 넘긴다. `truncated` 면 rotation / 재생성된 것이므로 화면을 비우고 다시 시작.
+#[tauri::command]
+fn read_log_content(
+    port_id: String,
+    offset: u64,
+    max_bytes: Option<usize>,
+    fingerprint: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<logs::LogChunk, String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    logs::read_chunk(&log_file, offset, max_bytes.unwrap_or(logs::DEFAULT_CHUNK_BYTES), fingerprint.as_deref())
+}
+
+/// watcher 가 로그에서 감지한 에러 배지 (port_id → 에러) — 이후 변경은 `log-error` 이벤트
+#[tauri::command]
+fn get_log_errors(state: State<AppState>) -> HashMap<String, logscan::LogError> {
+    state.log_errors.all()
+}
+
+/// 에러 배지 닫기
+#[tauri::command]
+fn clear_log_error(app_handle: tauri::AppHandle, state: State<AppState>, port_id: String) {
+    state.log_errors.set(&app_handle, &port_id, None);
+}
+
+/// 정규식으로 로그 검색 — `port_id` 가 없으면 모든 프로젝트
+#[tauri::command]
+async fn search_logs(
+    app_handle: tauri::AppHandle,
+    pattern: String,
+    port_id: Option<String>,
+    options: Option<logscan::SearchOptions>,
+) -> Result<logscan::SearchResult, String> {
+    let logs_dir = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs");
+    let port_ids: Vec<String> = match port_id {
+        Some(id) => vec![id],
+        None => load_ports(app_handle)?.into_iter().map(|p| p.id).collect(),
     };
-    let sliced = if safe_offset > 0 { &content[safe_offset..] } else { &content };
+    let targets: Vec<(String, std::path::PathBuf)> = port_ids
+        .into_iter()
+        .map(|id| {
+            let path = logs_dir.join(format!("{}.log", id));
+            (id, path)
+        })
+        .collect();
+    let options = options.unwrap_or_default();
+    tauri::async_runtime::spawn_blocking(move || logscan::search(&targets, &pattern, &options))
+        .await
+        .map_err(|e| e.to_string())?
+}
 
-    Ok(serde_json::json!({
-        "content": sliced,
-        "size": size,
-        "exists": true,
-        "offset": offset
-    }))
+/// 타임스탬프 / 스트림 태그가 붙은 로그 (`read_log_content` 와 같은 offset 방식, stamped 파일 기준)
+///
+/// `stream` 을 주면 그 스트림의 줄만 (예: `stderr`).
+#[tauri::command]
+fn read_stamped_log(
+    port_id: String,
+    offset: u64,
+    max_bytes: Option<usize>,
+    fingerprint: Option<String>,
+    stream: Option<capture::Stream>,
+    app_handle: tauri::AppHandle,
+) -> Result<capture::StampedChunk, String> {
+    let log_file = app_handle.path().app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("logs")
+        .join(format!("{}.log", port_id));
+    capture::read_stamped(&log_file, offset, max_bytes.unwrap_or(logs::DEFAULT_CHUNK_BYTES), fingerprint.as_deref(), stream)
 }
 
 /// Escape single quotes for use inside single-quoted shell strings.
@@ -1437,10 +1714,10 @@ fn open_in_chrome(url: String) -> Result<String, String> {
     Ok(format!("Chrome에서 열었습니다: {}", url))
 }
 
-#[tauri::command]
-fn import_ports_from_file(file_path: String) -> Result<Vec<PortInfo>, String> {
+/// 불러오기 파일 읽기 (배열 / 버전 envelope 모두 허용, `.toml` 은 `export_ports` 의 TOML 형식)
+fn read_import_file(file_path: &str) -> Result<Vec<PortInfo>, String> {
     // 파일이 존재하는지 확인
-    let path = std::path::PathBuf::from(&file_path);
+    let path = std::path::PathBuf::from(file_path);
     if !path.exists() {
         return Err("파일이 존재하지 않습니다".to_string());
     }
@@ -1449,11 +1726,97 @@ fn import_ports_from_file(file_path: String) -> Result<Vec<PortInfo>, String> {
     let content = fs::read_to_string(&path)
         .map_err(|e| format!("파일 읽기 실패: {}", e))?;
 
-    // JSON 파싱
-    let ports: Vec<PortInfo> = serde_json::from_str(&content)
+    match export::ExportFormat::from_path(&path) {
+        export::ExportFormat::Yaml | export::ExportFormat::Csv => {
+            return Err("YAML / CSV 는 불러올 수 없습니다 (JSON / TOML 만 지원)".to_string());
+        }
+        export::ExportFormat::Toml => {
+            let value: serde_json::Value = toml::from_str(&content)
+                .map_err(|e| format!("TOML 파싱 실패: {}", e))?;
+            return schema::ports_from_value(value).map_err(|e| format!("TOML 파싱 실패: {}", e));
+        }
+        export::ExportFormat::Json => {}
+    }
+
+    // JSON 파싱 (배열 / 버전 envelope 모두 허용)
+    let value: serde_json::Value = serde_json::from_str(&content)
         .map_err(|e| format!("JSON 파싱 실패: {}", e))?;
 
-    Ok(ports)
+    schema::ports_from_value(value).map_err(|e| format!("JSON 파싱 실패: {}", e))
+}
+
+#[tauri::command]
+fn import_ports_from_file(file_path: String) -> Result<Vec<PortInfo>, String> {
+    read_import_file(&file_path)
+}
+
+/// 현재 포트 목록을 파일로 내보내기 (형식이 없으면 확장자로 추정)
+///
+/// `portable` 이면 기기 전용 필드를 빼고 경로를 `workspace_root` 기준 상대경로 / `~` 로 바꾼다.
+/// 내보낸 항목 수를 반환.
+///
+/// 다시 불러올 수 있는 형식은 JSON / TOML 뿐 — YAML / CSV 는 열람 / 스프레드시트용
+/// (`import_ports_from_file`, `preview_import_ports` 는 거부함).
+#[tauri::command]
+fn export_ports(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+    format: Option<export::ExportFormat>,
+    portable: Option<bool>,
+    workspace_root: Option<String>,
+) -> Result<usize, String> {
+    let path = std::path::PathBuf::from(&file_path);
+    let format = format.unwrap_or_else(|| export::ExportFormat::from_path(&path));
+
+    let mut ports = load_ports(app_handle)?;
+    if portable.unwrap_or(false) {
+        export::make_portable(&mut ports, workspace_root.as_deref());
+    }
+    let content = export::render(&ports, format)?;
+    // 쓰는 도중 중단돼도 잘린 파일이 남지 않게
+    persist::write_atomic_no_backup(&path, content.as_bytes())?;
+
+    println!("[Export] Wrote {} ports as {:?} to {:?}", ports.len(), format, path);
+    Ok(ports.len())
+}
+
+/// 불러오기 미리보기 — 저장하지 않고 병합 결과(추가/변경/건너뜀/충돌)만 계산
+///
+/// `workspace_root` 는 portable 내보내기의 상대경로를 풀 기준 디렉토리.
+#[tauri::command]
+fn preview_import_ports(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+    strategy: Option<merge::MergeStrategy>,
+    workspace_root: Option<String>,
+) -> Result<merge::ImportPreview, String> {
+    let mut imported = read_import_file(&file_path)?;
+    export::resolve_paths(&mut imported, workspace_root.as_deref());
+    let existing = load_ports(app_handle)?;
+    Ok(merge::preview(&existing, imported, strategy.unwrap_or_default()))
+}
+
+/// 불러오기 적용 — 미리보기와 같은 계획을 현재 ports.json 기준으로 다시 계산해 저장
+///
+/// `strict` 는 `save_ports` 와 같음 (검증 에러가 있으면 저장 거부).
+#[tauri::command]
+fn apply_import_ports(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+    strategy: Option<merge::MergeStrategy>,
+    strict: Option<bool>,
+    workspace_root: Option<String>,
+) -> Result<merge::ImportPreview, String> {
+    let mut imported = read_import_file(&file_path)?;
+    export::resolve_paths(&mut imported, workspace_root.as_deref());
+    let existing = load_ports(app_handle.clone())?;
+    let mut plan = merge::preview(&existing, imported, strategy.unwrap_or_default());
+    println!(
+        "[Import] {:?}: {} added, {} changed, {} skipped, {} conflicts",
+        plan.strategy, plan.added.len(), plan.changed.len(), plan.skipped.len(), plan.conflicts.len()
+    );
+    plan.ports = save_ports(app_handle, plan.ports, None, strict)?.ports;
+    Ok(plan)
 }
 
 #[tauri::command]
@@ -2093,8 +2456,7 @@ fn get_global_shortcut(app: tauri::AppHandle) -> String {
     let path = app.path().app_data_dir()
         .map(|d| d.join("shortcut.json"));
     path.ok()
-        .and_then(|p| std::fs::read_to_string(p).ok())
-        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|p| persist::read_json::<serde_json::Value>(&p).ok().flatten())
         .and_then(|v| v["shortcut"].as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "CommandOrControl+Alt+P".to_string())
 }
@@ -2110,9 +2472,7 @@ fn set_global_shortcut(app: tauri::AppHandle, shortcut: String, old_shortcut: St
         .map_err(|e| e.to_string())?
         .join("shortcut.json");
     let json = serde_json::json!({ "shortcut": shortcut });
-    std::fs::write(&path, json.to_string())
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    persist::write_atomic(&path, json.to_string().as_bytes())
 }
 
 #[tauri::command]
@@ -2150,6 +2510,30 @@ fn detect_start_command(folder_path: String) -> Option<String> {
     None
 }
 
+/// 폴더의 docker-compose / Procfile / package.json workspaces 에서 프로젝트 후보 찾기
+///
+/// 이미 같은 folderPath + terminalCommand 로 등록된 항목은 제외. 저장하지 않으므로 프론트엔드에서
+/// 골라 `save_ports`(auto_assign 으로 빈 포트 채우기)로 추가한다.
+#[tauri::command]
+fn discover_projects(app_handle: tauri::AppHandle, folder_path: String) -> Result<Vec<discover::Discovered>, String> {
+    let existing = load_ports(app_handle)?;
+    let found = discover::discover(std::path::Path::new(&folder_path))?;
+    let same = |a: &Option<String>, b: &Option<String>| {
+        a.as_deref().map(|s| s.trim_end_matches('/')) == b.as_deref().map(|s| s.trim_end_matches('/'))
+    };
+    Ok(found
+        .into_iter()
+        .filter(|d| {
+            !existing.iter().any(|p| {
+                // workspace 패키지는 폴더 자체가 프로젝트라 커맨드 없이 등록된 항목도 같은 것으로 봄
+                same(&p.folder_path, &d.port_info.folder_path)
+                    && (p.terminal_command == d.port_info.terminal_command
+                        || (d.source == discover::Source::Workspace && p.terminal_command.is_none()))
+            })
+        })
+        .collect())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   let window_visible = Arc::new(Mutex::new(true));
@@ -2158,10 +2542,21 @@ pub fn run() {
   tauri::Builder::default()
     .manage(AppState {
         processes: Mutex::new(HashMap::new()),
+        supervisor: supervisor::Supervisor::default(),
+        health: health::HealthMonitor::default(),
+        ports: schema::PortsCache::default(),
+        log_errors: logscan::LogErrors::default(),
     })
     .invoke_handler(tauri::generate_handler![
         load_ports,
         save_ports,
+        validate_ports,
+        list_ports_history,
+        diff_ports_snapshot,
+        restore_ports_snapshot,
+        find_free_port,
+        load_port_ranges,
+        save_port_ranges,
         scan_command_files,
         open_app_data_dir,
         load_portal,
@@ -2170,18 +2565,36 @@ pub fn run() {
         save_workspace_roots,
         execute_command,
         detect_start_command,
+        discover_projects,
         stop_command,
         force_restart_command,
         detect_port,
         check_port_status,
+        get_port_listeners,
+        check_ports_status,
+        check_port_conflict,
+        get_process_states,
+        get_health_status,
+        check_health,
         build_app,
         install_app_to_applications,
         open_build_folder,
         open_folder,
         import_ports_from_file,
+        export_ports,
+        preview_import_ports,
+        apply_import_ports,
         open_in_chrome,
         open_log,
         read_log_content,
+        archive_log,
+        clear_logs,
+        list_log_runs,
+        read_stamped_log,
+        search_logs,
+        get_log_errors,
+        clear_log_error,
+        read_log_run,
         check_wsl,
         install_wsl,
         install_wsl_tmux,
@@ -2255,11 +2668,16 @@ pub fn run() {
         .map(|d| d.join("shortcut.json"))
         .ok();
       let saved = shortcut_path.as_ref()
-        .and_then(|p| std::fs::read_to_string(p).ok())
-        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|p| persist::read_json::<serde_json::Value>(p).ok().flatten())
         .and_then(|v| v["shortcut"].as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "CommandOrControl+Alt+P".to_string());
       let _ = app.global_shortcut().register(saved.as_str());
+      // 이전 실행에서 띄운 서버 중 아직 살아있는 것 다시 추적 (재시작/업데이트 후에도 stop 가능)
+      tracked::adopt(app.handle());
+      // 프로세스/포트/로그 변화 감시 → 이벤트 push (UI 폴링 대체)
+      watcher::start(app.handle().clone());
+      // healthCheck 가 선언된 서버 주기적 프로브
+      health::start(app.handle().clone());
       Ok(())
     })
     .build(tauri::generate_context!())
@@ -0,0 +1,347 @@
+//! 서버 로그 파일 (`logs/<port_id>.log`) 조회 / rotation
+//!
+//! rotation 후 파일 구성: `<id>.log` (현재) → `<id>.log.1` → `<id>.log.2.gz` … `<id>.log.<keep>.gz`
+
+use std::fs;
+use std::hash::{DefaultHasher, Hasher};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+/// 마지막 `max_lines` 줄 — 큰 로그 파일도 끝부분만 읽는다
+pub fn tail(path: &Path, max_lines: usize) -> String {
+    const MAX_BYTES: u64 = 64 * 1024;
+
+    let Ok(mut file) = fs::File::open(path) else { return String::new() };
+    let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = size.saturating_sub(MAX_BYTES);
+    if file.seek(SeekFrom::Start(start)).is_err() {
+        return String::new();
+    }
+    let mut buf = Vec::new();
+    if file.read_to_end(&mut buf).is_err() {
+        return String::new();
+    }
+    let text = String::from_utf8_lossy(&buf);
+    let lines: Vec<&str> = text.lines().collect();
+    // 중간부터 읽었으면 첫 줄은 잘려 있을 수 있으므로 제외
+    let skip_partial = usize::from(start > 0 && lines.len() > 1);
+    let from = lines.len().saturating_sub(max_lines).max(skip_partial);
+    lines[from..].join("\n")
+}
+
+/// `read_chunk` 기본 최대 크기
+pub const DEFAULT_CHUNK_BYTES: usize = 256 * 1024;
+/// fingerprint 에 쓰는 offset 직전 바이트 수
+const FINGERPRINT_BYTES: u64 = 64;
+
+/// `read_log_content` 응답
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogChunk {
+    pub content: String,
+    /// 현재 파일 크기
+    pub size: u64,
+    pub exists: bool,
+    /// `content` 가 시작하는 위치
+    pub offset: u64,
+    /// 다음 요청에 넘길 offset
+    pub next_offset: u64,
+    /// 파일이 요청 offset 보다 줄어들었거나 fingerprint 가 다름 (rotation / truncate / 재생성)
+    /// — 기존 출력을 버리고 `content` 로 다시 시작
+    pub truncated: bool,
+    /// 다음 요청에 `next_offset` 과 함께 넘길 값 — 파일 identity + `next_offset` 직전 내용의 해시
+    pub fingerprint: String,
+}
+
+/// 파일 identity — Unix 는 device + inode, 그 밖에는 생성 시각
+fn file_identity(meta: &fs::Metadata) -> u64 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        meta.dev().rotate_left(32) ^ meta.ino()
+    }
+    #[cfg(not(unix))]
+    {
+        meta.created()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_nanos() as u64)
+    }
+}
+
+/// `pos` 직전 `FINGERPRINT_BYTES` 의 해시 + identity
+///
+/// copytruncate rotation 은 inode 가 그대로이므로, 잘린 뒤 예전 offset 을 넘어 다시 커져도
+/// 직전 내용이 달라져 구분된다.
+fn fingerprint(file: &mut fs::File, identity: u64, pos: u64) -> io::Result<String> {
+    let from = pos.saturating_sub(FINGERPRINT_BYTES);
+    file.seek(SeekFrom::Start(from))?;
+    let mut buf = Vec::with_capacity((pos - from) as usize);
+    file.take(pos - from).read_to_end(&mut buf)?;
+    let mut hasher = DefaultHasher::new();
+    hasher.write(&buf);
+    Ok(format!("{:x}-{:x}", identity, hasher.finish()))
+}
+
+/// 끝에 잘린 UTF-8 멀티바이트 문자가 있으면 그 시작 위치 (다음 읽기로 미룸)
+fn incomplete_utf8_tail(buf: &[u8]) -> usize {
+    for back in 1..=buf.len().min(3) {
+        let byte = buf[buf.len() - back];
+        if byte & 0xC0 == 0x80 {
+            continue; // continuation byte
+        }
+        let needed = match byte {
+            b if b >= 0xF0 => 4,
+            b if b >= 0xE0 => 3,
+            b if b >= 0xC0 => 2,
+            _ => 1,
+        };
+        return if back < needed { buf.len() - back } else { buf.len() };
+    }
+    buf.len()
+}
+
+/// `offset` 이후 새로 쓰인 부분만 읽기 (최대 `max_bytes`)
+///
+/// - offset 0 또는 파일이 바뀐 경우 (줄어듦 / `fingerprint` 불일치): 마지막 `max_bytes` 만 (잘린 첫 줄 제외)
+/// - 한도에 걸리면 마지막 줄바꿈까지만 반환하고 나머지는 다음 호출에서
+/// - 한도가 문자 하나보다 작아도 최소 한 문자는 반환 (`next_offset` 이 항상 전진)
+/// - 잘못된 UTF-8 은 U+FFFD 로 대체
+pub fn read_chunk(path: &Path, offset: u64, max_bytes: usize, expected: Option<&str>) -> Result<LogChunk, String> {
+    let mut file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(LogChunk {
+                content: String::new(),
+                size: 0,
+                exists: false,
+                offset: 0,
+                next_offset: 0,
+                truncated: false,
+                fingerprint: String::new(),
+            });
+        }
+        Err(e) => return Err(format!("Failed to open log file: {}", e)),
+    };
+    let meta = file.metadata().map_err(|e| e.to_string())?;
+    let size = meta.len();
+    let identity = file_identity(&meta);
+    let max_bytes = max_bytes.max(1) as u64;
+
+    let truncated = offset > size
+        || match expected.filter(|_| offset > 0) {
+            Some(expected) => fingerprint(&mut file, identity, offset).map_err(|e| e.to_string())? != expected,
+            None => false,
+        };
+    let from_tail = offset == 0 || truncated;
+    let start = if from_tail { size.saturating_sub(max_bytes) } else { offset };
+    let end = size.min(start + max_bytes);
+
+    file.seek(SeekFrom::Start(start)).map_err(|e| e.to_string())?;
+    let mut buf = Vec::with_capacity((end - start) as usize);
+    (&mut file).take(end - start).read_to_end(&mut buf).map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    // 파일 중간부터 읽었으면 첫 줄은 잘려 있을 수 있으므로 제외
+    let mut skip = 0;
+    if from_tail && start > 0 {
+        if let Some(pos) = buf.iter().position(|b| *b == b'\n') {
+            skip = pos + 1;
+        }
+    }
+    // 한도에 걸렸으면 줄 단위로 끊음 (줄바꿈이 없으면 그대로)
+    let mut keep = buf.len();
+    if end < size {
+        if let Some(pos) = buf[skip..].iter().rposition(|b| *b == b'\n') {
+            keep = skip + pos + 1;
+        }
+    }
+    keep = skip + incomplete_utf8_tail(&buf[skip..keep]);
+
+    // 창 안에 잘린 문자 하나뿐 (한도 < 문자 길이) — 나머지 바이트를 더 읽어 최소 한 문자는 반환
+    if keep == skip && end < size {
+        let extra = (size - end).min(3);
+        (&mut file).take(extra).read_to_end(&mut buf).map_err(|e| format!("Failed to read log file: {}", e))?;
+        keep = skip + incomplete_utf8_tail(&buf[skip..]);
+        if keep == skip && end + extra < size {
+            keep = buf.len(); // 깨진 바이트열 — U+FFFD 로라도 넘어감
+        }
+    }
+
+    let next_offset = start + keep as u64;
+    Ok(LogChunk {
+        content: String::from_utf8_lossy(&buf[skip..keep]).into_owned(),
+        size,
+        exists: true,
+        offset: start + skip as u64,
+        next_offset,
+        truncated,
+        fingerprint: fingerprint(&mut file, identity, next_offset).map_err(|e| e.to_string())?,
+    })
+}
+
+/// 프로젝트별 로그 보존 설정 (`PortInfo.logRetention`, 0 이면 해당 조건 끔)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LogRetention {
+    /// 현재 로그가 이 크기를 넘으면 rotation
+    pub max_size_mb: u64,
+    /// 마지막 rotation 후 이 시간이 지나면 rotation
+    pub max_age_hours: u64,
+    /// 보관할 이전 로그 수 (`.log.1` ~ `.log.N`)
+    pub keep: usize,
+    /// 이보다 오래된 보관 로그 삭제
+    pub retention_days: u64,
+    /// `.log.2` 부터 gzip 압축
+    pub compress: bool,
+}
+
+impl Default for LogRetention {
+    fn default() -> Self {
+        LogRetention { max_size_mb: 10, max_age_hours: 24, keep: 5, retention_days: 14, compress: true }
+    }
+}
+
+/// 보관 로그 경로 (`<id>.log.1`, `<id>.log.2.gz` …)
+fn archive_path(path: &Path, index: usize, gz: bool) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}{}", index, if gz { ".gz" } else { "" }));
+    path.with_file_name(name)
+}
+
+/// 압축 여부와 무관하게 존재하는 보관 로그
+fn existing_archive(path: &Path, index: usize) -> Option<PathBuf> {
+    [archive_path(path, index, false), archive_path(path, index, true)]
+        .into_iter()
+        .find(|p| p.exists())
+}
+
+/// 보관 로그 목록 (최신순)
+pub fn archives(path: &Path) -> Vec<PathBuf> {
+    (1..).map_while(|i| existing_archive(path, i)).collect()
+}
+
+/// 오래된 보관 로그부터 현재 로그까지 (시간순)
+pub fn chronological(path: &Path) -> Vec<PathBuf> {
+    let mut files = archives(path);
+    files.reverse();
+    files.push(path.to_path_buf());
+    files
+}
+
+/// 로그 파일 읽기 — `.gz` 보관 로그는 압축을 풀면서
+pub fn open_reader(path: &Path) -> io::Result<Box<dyn Read>> {
+    let file = fs::File::open(path)?;
+    if path.extension().is_some_and(|e| e == "gz") {
+        Ok(Box::new(GzDecoder::new(file)))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+fn gzip(src: &Path, dest: &Path) -> std::io::Result<()> {
+    let mut input = fs::File::open(src)?;
+    let mut encoder = GzEncoder::new(fs::File::create(dest)?, Compression::default());
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.sync_all()
+}
+
+/// 현재 로그를 `.log.1` 로 보관하고 비움 (copytruncate)
+///
+/// 실행 중인 서버는 같은 파일을 O_APPEND 로 열고 있어 rename 하면 계속 옛 파일에 쓰므로,
+/// 복사 후 같은 inode 를 0 으로 자른다. append 모드라 자른 뒤의 쓰기는 파일 처음부터 이어진다.
+/// 복사와 truncate 사이에 쓰인 몇 줄은 유실될 수 있다.
+pub fn rotate(path: &Path, retention: &LogRetention) -> Result<Option<PathBuf>, String> {
+    let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    if size == 0 {
+        return Ok(None);
+    }
+    let keep = retention.keep.max(1);
+
+    // 가장 오래된 것부터 한 칸씩 밀기 (keep 을 넘는 것은 삭제)
+    for index in (1..=archives(path).len()).rev() {
+        let Some(src) = existing_archive(path, index) else { continue };
+        if index >= keep {
+            let _ = fs::remove_file(&src);
+            continue;
+        }
+        let gz = src.extension().is_some_and(|e| e == "gz");
+        if index == 1 && retention.compress && !gz {
+            gzip(&src, &archive_path(path, 2, true)).map_err(|e| format!("Failed to compress {:?}: {}", src, e))?;
+            let _ = fs::remove_file(&src);
+        } else {
+            fs::rename(&src, archive_path(path, index + 1, gz)).map_err(|e| format!("Failed to rotate {:?}: {}", src, e))?;
+        }
+    }
+
+    let first = archive_path(path, 1, false);
+    fs::copy(path, &first).map_err(|e| format!("Failed to archive {:?}: {}", path, e))?;
+    fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|f| f.set_len(0))
+        .map_err(|e| format!("Failed to truncate {:?}: {}", path, e))?;
+    Ok(Some(first))
+}
+
+/// 크기 / 경과 시간 조건이면 rotation, 보존 기간 지난 보관 로그 삭제
+pub fn rotate_if_needed(path: &Path, retention: &LogRetention) -> Result<bool, String> {
+    let Ok(meta) = fs::metadata(path) else { return Ok(false) };
+    prune_archives(path, retention);
+
+    let too_big = retention.max_size_mb > 0 && meta.len() > retention.max_size_mb * 1024 * 1024;
+    // `.log.1` 은 rotation 때 새로 쓰므로 mtime = 마지막 rotation 시각
+    let last_rotation = fs::metadata(archive_path(path, 1, false))
+        .and_then(|m| m.modified())
+        .or_else(|_| meta.created());
+    let too_old = retention.max_age_hours > 0
+        && meta.len() > 0
+        && last_rotation
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age >= Duration::from_secs(retention.max_age_hours * 3600));
+
+    if too_big || too_old {
+        rotate(path, retention)?;
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+fn prune_archives(path: &Path, retention: &LogRetention) {
+    if retention.retention_days == 0 {
+        return;
+    }
+    let max_age = Duration::from_secs(retention.retention_days * 24 * 3600);
+    for archive in archives(path) {
+        let expired = fs::metadata(&archive)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age > max_age);
+        if expired {
+            let _ = fs::remove_file(&archive);
+        }
+    }
+}
+
+/// 현재 로그 비우기 (실행 중이어도 안전), `include_archives` 면 보관 로그도 삭제
+pub fn clear(path: &Path, include_archives: bool) -> Result<(), String> {
+    if path.exists() {
+        fs::OpenOptions::new()
+            .write(true)
+            .open(path)
+            .and_then(|f| f.set_len(0))
+            .map_err(|e| format!("Failed to clear {:?}: {}", path, e))?;
+    }
+    if include_archives {
+        for archive in archives(path) {
+            fs::remove_file(&archive).map_err(|e| format!("Failed to remove {:?}: {}", archive, e))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,250 @@
+//! 로그 검색 + 에러 패턴 감지
+//!
+//! `search` 는 정규식으로 프로젝트 로그(보관 로그 포함 가능)를 훑어 앞뒤 문맥과 함께 반환한다.
+//!
+//! `detect` 는 watcher 가 새로 추가된 로그에 돌리는 내장 감지기:
+//!
+//! | kind                   | 패턴 예                                              |
+//! |------------------------|------------------------------------------------------|
+//! | `port-in-use`          | `EADDRINUSE`, `address already in use`               |
+//! | `module-not-found`     | `Cannot find module`, `ModuleNotFoundError`          |
+//! | `panic`                | `thread 'main' panicked at`, Go `panic:`             |
+//! | `unhandled-rejection`  | `UnhandledPromiseRejection`, `unhandledRejection`    |
+//! | `python-traceback`     | `Traceback (most recent call last):`                 |
+//!
+//! 감지되면 `AppState` 의 `LogErrors` 에 배지를 기록하고 `log-error` 이벤트를 보낸다
+//! (런타임 상태라 ports.json 에는 저장하지 않음 — 앱을 다시 켜면 사라짐).
+//! 새 run 헤더(`runs.rs`)가 보이면 재시작된 것이므로 배지를 지운다.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+use crate::{logs, procinfo, runs};
+
+/// 결과 한 줄의 최대 길이 (번들된 JS 한 줄이 수 MB 인 경우)
+const MAX_LINE_CHARS: usize = 500;
+pub const DEFAULT_CONTEXT: usize = 2;
+pub const DEFAULT_MAX_MATCHES: usize = 500;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorKind {
+    PortInUse,
+    ModuleNotFound,
+    Panic,
+    UnhandledRejection,
+    PythonTraceback,
+}
+
+/// 프로젝트 카드의 에러 배지
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LogError {
+    pub kind: ErrorKind,
+    /// 감지된 줄
+    pub line: String,
+    pub detected_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogMatch {
+    pub port_id: String,
+    /// 보관 로그면 `.log.2.gz` 등
+    pub file: String,
+    /// 파일 안의 줄 번호 (1부터)
+    pub line_number: usize,
+    pub text: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub matches: Vec<LogMatch>,
+    /// `max_matches` 에 도달해 중단함
+    pub limited: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchOptions {
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// 앞뒤 문맥 줄 수 (기본 2)
+    pub context: Option<usize>,
+    /// `.log.1`, `.log.N.gz` 까지 검색
+    #[serde(default)]
+    pub include_archives: bool,
+    pub max_matches: Option<usize>,
+}
+
+fn clip_line(line: &str) -> String {
+    match line.char_indices().nth(MAX_LINE_CHARS) {
+        Some((i, _)) => format!("{}…", &line[..i]),
+        None => line.to_string(),
+    }
+}
+
+/// 로그 파일들(`(port_id, log_file)`)에서 정규식 검색
+pub fn search(logs_to_search: &[(String, PathBuf)], pattern: &str, options: &SearchOptions) -> Result<SearchResult, String> {
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(options.case_insensitive)
+        .build()
+        .map_err(|e| format!("Invalid pattern: {}", e))?;
+    let context = options.context.unwrap_or(DEFAULT_CONTEXT);
+    let max_matches = options.max_matches.unwrap_or(DEFAULT_MAX_MATCHES);
+
+    let mut matches = Vec::new();
+    let mut limited = false;
+    for (port_id, log_file) in logs_to_search {
+        let files = if options.include_archives {
+            logs::chronological(log_file)
+        } else {
+            vec![log_file.clone()]
+        };
+        for file in files {
+            let Ok(reader) = logs::open_reader(&file) else { continue };
+            let file_name = file.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            let mut before: VecDeque<String> = VecDeque::with_capacity(context + 1);
+            // after 문맥을 아직 채우는 중인 결과 (matches 안의 index)
+            let mut open: Vec<usize> = Vec::new();
+            for (i, line) in BufReader::new(reader).split(b'\n').enumerate() {
+                let Ok(line) = line else { break };
+                let line = String::from_utf8_lossy(&line);
+                let line = clip_line(line.trim_end_matches('\r'));
+
+                open.retain(|&m| {
+                    let entry: &mut LogMatch = &mut matches[m];
+                    entry.after.push(line.clone());
+                    entry.after.len() < context
+                });
+                if regex.is_match(&line) {
+                    if matches.len() >= max_matches {
+                        limited = true;
+                    } else {
+                        matches.push(LogMatch {
+                            port_id: port_id.clone(),
+                            file: file_name.clone(),
+                            line_number: i + 1,
+                            text: line.clone(),
+                            before: before.iter().cloned().collect(),
+                            after: Vec::new(),
+                        });
+                        if context > 0 {
+                            open.push(matches.len() - 1);
+                        }
+                    }
+                }
+                if limited && open.is_empty() {
+                    return Ok(SearchResult { matches, limited });
+                }
+                if context > 0 {
+                    if before.len() == context {
+                        before.pop_front();
+                    }
+                    before.push_back(line);
+                }
+            }
+        }
+    }
+    Ok(SearchResult { matches, limited })
+}
+
+fn detectors() -> &'static [(ErrorKind, Regex)] {
+    static DETECTORS: OnceLock<Vec<(ErrorKind, Regex)>> = OnceLock::new();
+    DETECTORS.get_or_init(|| {
+        [
+            (ErrorKind::PortInUse, r"(?i)EADDRINUSE|address already in use|port \d+ is already in use"),
+            (ErrorKind::ModuleNotFound, r"Cannot find module|Module not found|ERR_MODULE_NOT_FOUND|ModuleNotFoundError"),
+            (ErrorKind::Panic, r"thread '[^']*' panicked at|^panic: "),
+            (ErrorKind::UnhandledRejection, r"(?i)unhandled ?promise ?rejection|unhandledRejection"),
+            (ErrorKind::PythonTraceback, r"^Traceback \(most recent call last\):"),
+        ]
+        .into_iter()
+        .map(|(kind, pattern)| (kind, Regex::new(pattern).expect("valid detector pattern")))
+        .collect()
+    })
+}
+
+/// `log-error` 이벤트 — `error` 가 없으면 배지 해제
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogErrorEvent {
+    pub port_id: String,
+    pub error: Option<LogError>,
+}
+
+/// 프로젝트별 최근 로그 에러 보관
+#[derive(Default)]
+pub struct LogErrors {
+    errors: Mutex<HashMap<String, LogError>>,
+}
+
+impl LogErrors {
+    /// port_id → 에러
+    pub fn all(&self) -> HashMap<String, LogError> {
+        self.errors.lock().unwrap().clone()
+    }
+
+    /// 배지 설정 / 해제 — 바뀌었으면 `log-error` 이벤트 전송 후 true
+    pub fn set(&self, app: &tauri::AppHandle, port_id: &str, error: Option<LogError>) -> bool {
+        let mut errors = self.errors.lock().unwrap();
+        if errors.get(port_id) == error.as_ref() {
+            return false;
+        }
+        match &error {
+            Some(error) => errors.insert(port_id.to_string(), error.clone()),
+            None => errors.remove(port_id),
+        };
+        let _ = app.emit("log-error", LogErrorEvent { port_id: port_id.to_string(), error });
+        true
+    }
+
+    /// 삭제된 프로젝트 제거
+    pub fn retain(&self, port_ids: &[&str]) {
+        self.errors.lock().unwrap().retain(|id, _| port_ids.contains(&id.as_str()));
+    }
+}
+
+/// 감지 결과 — 배지를 바꿀 필요가 없으면 None
+#[derive(Debug, Clone, PartialEq)]
+pub enum Detection {
+    /// 새 에러 (가장 마지막에 감지된 줄)
+    Error(LogError),
+    /// 에러 없이 새 run 이 시작됨 → 배지 해제
+    Restarted,
+}
+
+/// 새로 추가된 로그 텍스트에 감지기 적용
+///
+/// 한 덩어리 안에 run 헤더가 있으면 그 이후만 본다 (이전 실행의 에러는 지난 일).
+pub fn detect(text: &str) -> Option<Detection> {
+    let mut result = None;
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        if runs::is_run_start(line) {
+            result = Some(Detection::Restarted);
+            continue;
+        }
+        if let Some((kind, _)) = detectors().iter().find(|(_, re)| re.is_match(line)) {
+            result = Some(Detection::Error(LogError {
+                kind: *kind,
+                line: clip_line(line.trim()),
+                detected_at: procinfo::now_secs(),
+            }));
+        } else if let Some(Detection::Error(error)) = result.as_mut() {
+            // traceback 은 들여쓰기 없는 첫 줄이 예외 (`ValueError: ...`)
+            if error.kind == ErrorKind::PythonTraceback && error.line.starts_with("Traceback") && !line.starts_with(char::is_whitespace) && !line.is_empty() {
+                error.line = clip_line(line.trim());
+            }
+        }
+    }
+    result
+}
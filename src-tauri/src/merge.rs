@@ -0,0 +1,142 @@
+//! 파일에서 불러온 포트 목록을 기존 ports.json 에 병합
+//!
+//! | strategy         | 같은 항목이 이미 있을 때                                  |
+//! |------------------|-----------------------------------------------------------|
+//! | `skip-existing`  | 같은 id 는 건너뜀 (기본, 예전 동작)                       |
+//! | `overwrite`      | 같은 id 를 불러온 값으로 교체                             |
+//! | `keep-both`      | 같은 id 면 새 id(`<id>-import`, `-import-2`…)로 추가      |
+//! | `match-folder`   | folderPath 가 같은 항목을 교체 (기존 id 유지), 없으면 id  |
+//!
+//! 먼저 `preview` 로 결과를 보여주고, 사용자가 확인하면 같은 계획을 저장한다.
+
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::history::{self, ChangedEntry, EntryRef};
+use crate::validation::{self, Severity, ValidationIssue};
+use crate::PortInfo;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    #[default]
+    SkipExisting,
+    Overwrite,
+    KeepBoth,
+    MatchFolder,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedEntry {
+    /// 파일에 있던 id
+    pub original_id: String,
+    /// 새로 부여한 id
+    pub id: String,
+    pub name: String,
+}
+
+/// 병합 결과 미리보기 (적용 후에도 같은 형식으로 반환)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    pub strategy: MergeStrategy,
+    /// 파일의 항목 수
+    pub total: usize,
+    pub added: Vec<EntryRef>,
+    pub changed: Vec<ChangedEntry>,
+    pub skipped: Vec<EntryRef>,
+    /// keep-both 등으로 id 가 바뀐 항목
+    pub renamed: Vec<RenamedEntry>,
+    /// 병합 후 불러온 항목에 걸린 검증 에러 (같은 포트를 두 프로젝트가 사용 등)
+    pub conflicts: Vec<ValidationIssue>,
+    /// 병합된 전체 목록
+    pub ports: Vec<PortInfo>,
+}
+
+fn entry(p: &PortInfo) -> EntryRef {
+    EntryRef { id: p.id.clone(), name: p.name.clone() }
+}
+
+fn same_folder(a: &Option<String>, b: &Option<String>) -> bool {
+    let normalize = |p: &Option<String>| {
+        p.as_deref()
+            .map(|s| s.trim().trim_end_matches('/').to_string())
+            .filter(|s| !s.is_empty())
+    };
+    match (normalize(a), normalize(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn unique_id(base: &str, taken: &HashSet<String>) -> String {
+    let first = format!("{}-import", base);
+    if !taken.contains(&first) {
+        return first;
+    }
+    (2..)
+        .map(|n| format!("{}-import-{}", base, n))
+        .find(|id| !taken.contains(id))
+        .expect("unbounded id search")
+}
+
+/// 병합 계획 계산 (저장하지 않음)
+pub fn preview(existing: &[PortInfo], imported: Vec<PortInfo>, strategy: MergeStrategy) -> ImportPreview {
+    let total = imported.len();
+    let mut merged: Vec<PortInfo> = existing.to_vec();
+    let mut taken: HashSet<String> = merged.iter().map(|p| p.id.clone()).collect();
+    let mut touched: HashSet<String> = HashSet::new();
+    let mut skipped = Vec::new();
+    let mut renamed = Vec::new();
+
+    for mut incoming in imported {
+        let by_id = merged.iter().position(|p| p.id == incoming.id);
+        let target = match strategy {
+            MergeStrategy::MatchFolder => merged
+                .iter()
+                .position(|p| same_folder(&p.folder_path, &incoming.folder_path))
+                .or(by_id),
+            _ => by_id,
+        };
+        // 같은 파일 안에서 이미 병합한 항목과 겹치면 덮어쓰지 않고 새 id 로
+        let target = target.filter(|i| !touched.contains(&merged[*i].id));
+
+        match (strategy, target) {
+            (MergeStrategy::SkipExisting, Some(_)) => skipped.push(entry(&incoming)),
+            (MergeStrategy::Overwrite | MergeStrategy::MatchFolder, Some(i)) => {
+                incoming.id = merged[i].id.clone();
+                touched.insert(incoming.id.clone());
+                merged[i] = incoming;
+            }
+            _ => {
+                if taken.contains(&incoming.id) || incoming.id.trim().is_empty() {
+                    let base = if incoming.id.trim().is_empty() { "port" } else { incoming.id.as_str() };
+                    let id = unique_id(base, &taken);
+                    renamed.push(RenamedEntry { original_id: incoming.id.clone(), id: id.clone(), name: incoming.name.clone() });
+                    incoming.id = id;
+                }
+                taken.insert(incoming.id.clone());
+                touched.insert(incoming.id.clone());
+                merged.push(incoming);
+            }
+        }
+    }
+
+    let diff = history::diff(existing, &merged);
+    let conflicts = validation::validate(&merged)
+        .into_iter()
+        .filter(|i| i.severity == Severity::Error && touched.contains(&i.port_id))
+        .collect();
+
+    ImportPreview {
+        strategy,
+        total,
+        added: diff.added,
+        changed: diff.changed,
+        skipped,
+        renamed,
+        conflicts,
+        ports: merged,
+    }
+}
@@ -0,0 +1,117 @@
+//! 상태 파일(ports.json 등) 안전 저장
+//!
+//! `fs::write` 는 쓰는 도중 크래시 / 디스크 부족이면 잘린 JSON 을 남기고, 다음 로드에서
+//! 파싱에 실패해 목록 전체가 사라진 것처럼 보인다. 여기서는
+//!
+//! 1. `<file>.tmp.<pid>.<n>` 에 쓰고 fsync (쓰는 쪽마다 다른 이름 — 동시에 저장해도 서로의 temp 를 건드리지 않음)
+//! 2. 기존 파일을 `<file>.bak` 으로 보관
+//! 3. rename 으로 교체 (같은 디렉토리 안이므로 원자적) 후 디렉토리 fsync
+//!
+//! 읽을 때 본 파일이 없거나 파싱에 실패하면 `.bak` 으로 복구한다.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// 백업 파일 경로 (`ports.json` → `ports.json.bak`)
+pub fn backup_path(path: &Path) -> PathBuf {
+    sibling(path, ".bak")
+}
+
+/// temp 파일 → fsync → 기존 파일 `.bak` 보관 → rename
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), String> {
+    replace(path, contents, true)
+}
+
+/// `write_atomic` 과 같되 `.bak` 을 남기지 않음 (사용자가 고른 내보내기 경로 등)
+pub fn write_atomic_no_backup(path: &Path, contents: &[u8]) -> Result<(), String> {
+    replace(path, contents, false)
+}
+
+fn replace(path: &Path, contents: &[u8], keep_backup: bool) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {:?}: {}", dir, e))?;
+    }
+    let tmp = sibling(path, &format!(".tmp.{}.{}", std::process::id(), TMP_COUNTER.fetch_add(1, Ordering::Relaxed)));
+    let write_tmp = || -> std::io::Result<()> {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(contents)?;
+        file.sync_all()
+    };
+    if let Err(e) = write_tmp() {
+        let _ = fs::remove_file(&tmp);
+        return Err(format!("Failed to write {:?}: {}", tmp, e));
+    }
+
+    if keep_backup && path.exists() {
+        // 백업 실패는 저장 자체를 막지 않음
+        if let Err(e) = fs::copy(path, backup_path(path)) {
+            println!("[Persist] Failed to back up {:?}: {}", path, e);
+        }
+    }
+    fs::rename(&tmp, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        format!("Failed to replace {:?}: {}", path, e)
+    })?;
+
+    // rename 자체를 디스크에 반영 (Unix 전용 — Windows 는 디렉토리를 열 수 없음)
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        if let Ok(dir) = fs::File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    Ok(())
+}
+
+/// JSON 으로 직렬화해 원자적으로 저장
+pub fn write_json<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    // 이미 깨진 파일로 정상 백업을 덮어쓰지 않음
+    let existing_valid = fs::read_to_string(path)
+        .map(|c| serde_json::from_str::<serde_json::Value>(&c).is_ok())
+        .unwrap_or(false);
+    replace(path, content.as_bytes(), existing_valid)
+}
+
+/// JSON 읽기 — 본 파일이 없거나 깨졌으면 `.bak` 사용. 둘 다 없으면 `Ok(None)`
+///
+/// 본 파일이 깨졌고 백업도 없으면 원래 파싱 에러를 반환한다.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+    let primary = match fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => Err(format!("Failed to parse {:?}: {}", path, e)),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to read {:?}: {}", path, e)),
+    };
+
+    let backup = backup_path(path);
+    let recovered = fs::read_to_string(&backup)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+    match (primary, recovered) {
+        (Err(e), Some(value)) => {
+            println!("[Persist] {} — recovered from {:?}", e, backup);
+            Ok(Some(value))
+        }
+        (Ok(()), Some(value)) => {
+            println!("[Persist] {:?} missing — recovered from {:?}", path, backup);
+            Ok(Some(value))
+        }
+        (Err(e), None) => Err(e),
+        (Ok(()), None) => Ok(None),
+    }
+}
@@ -0,0 +1,96 @@
+//! 빈 포트 할당
+//!
+//! OS 에서 이미 바인딩된 포트와 ports.json 에 등록된 포트를 모두 피해서 고른다.
+//! 카테고리별 범위는 `port-ranges.json` 에 저장 (없으면 기본 범위 3000~3999).
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, TcpListener};
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+use crate::{persist, sockets};
+
+const FILE_NAME: &str = "port-ranges.json";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl PortRange {
+    fn ports(&self) -> impl Iterator<Item = u16> {
+        self.start..=self.end
+    }
+}
+
+fn default_range() -> PortRange {
+    PortRange { start: 3000, end: 3999 }
+}
+
+/// `port-ranges.json` — 카테고리에 범위가 없으면 `default` 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortRanges {
+    #[serde(default = "default_range")]
+    pub default: PortRange,
+    #[serde(default)]
+    pub categories: HashMap<String, PortRange>,
+}
+
+impl Default for PortRanges {
+    fn default() -> Self {
+        PortRanges { default: default_range(), categories: HashMap::new() }
+    }
+}
+
+impl PortRanges {
+    pub fn range_for(&self, category: Option<&str>) -> PortRange {
+        category
+            .and_then(|c| self.categories.get(c))
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let invalid = std::iter::once(("default", &self.default))
+            .chain(self.categories.iter().map(|(c, r)| (c.as_str(), r)))
+            .find(|(_, r)| r.start == 0 || r.start > r.end);
+        match invalid {
+            Some((name, r)) => Err(format!("Invalid port range for {}: {}-{}", name, r.start, r.end)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn load_ranges(app: &tauri::AppHandle) -> Result<PortRanges, String> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    Ok(persist::read_json(&app_data_dir.join(FILE_NAME))?.unwrap_or_default())
+}
+
+pub fn save_ranges(app: &tauri::AppHandle, ranges: &PortRanges) -> Result<(), String> {
+    ranges.validate()?;
+    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    persist::write_json(&app_data_dir.join(FILE_NAME), ranges)
+}
+
+/// 지금 바로 bind 할 수 있는 포트인지
+fn is_bindable(port: u16) -> bool {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, port)).is_ok()
+}
+
+/// 후보 중 처음으로 비어 있는 포트 — `reserved`(다른 PortInfo 포트)와 OS 리스너를 모두 피함
+///
+/// 소켓 테이블은 한 번만 스캔하고, 마지막으로 실제 bind 가능 여부를 확인한다.
+pub fn first_free(candidates: impl IntoIterator<Item = u16>, reserved: &[u16]) -> Option<u16> {
+    let candidates: Vec<u16> = candidates.into_iter().filter(|p| *p != 0 && !reserved.contains(p)).collect();
+    let listeners = sockets::listeners_by_port(&candidates);
+    candidates
+        .into_iter()
+        .find(|p| listeners.get(p).is_none_or(|l| l.is_empty()) && is_bindable(*p))
+}
+
+/// 카테고리 범위 안의 빈 포트 (`preferred` 가 비어 있으면 우선)
+pub fn find_free(ranges: &PortRanges, category: Option<&str>, preferred: Option<u16>, reserved: &[u16]) -> Option<u16> {
+    first_free(preferred.into_iter().chain(ranges.range_for(category).ports()), reserved)
+}
@@ -0,0 +1,240 @@
+//! PID → 프로세스 메타데이터 (이름, 커맨드라인, 시작 시각) 조회
+//!
+//! - Linux: `/proc/<pid>/comm`, `/proc/<pid>/cmdline`, `/proc/<pid>/stat` (starttime) + `/proc/stat` (btime)
+//! - macOS: `proc_pidinfo(PROC_PIDTBSDINFO)`, `sysctl(KERN_PROCARGS2)`
+//! - 그 외 플랫폼: None
+
+/// 프로세스 이름 (실행 파일명, 경로 제외)
+#[cfg(target_os = "linux")]
+pub fn process_name(pid: u32) -> Option<String> {
+    let comm = std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
+    let comm = comm.trim();
+    if comm.is_empty() { None } else { Some(comm.to_string()) }
+}
+
+/// 프로세스 시작 시각 (Unix epoch 초)
+#[cfg(target_os = "linux")]
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // comm 필드에 공백/괄호가 들어갈 수 있으므로 마지막 ')' 이후부터 파싱
+    // ')' 뒤 첫 필드가 3번(state) → 22번(starttime)은 인덱스 19
+    let after_comm = &stat[stat.rfind(')')? + 1..];
+    let start_ticks: u64 = after_comm.split_whitespace().nth(19)?.parse().ok()?;
+
+    let boot_time: u64 = std::fs::read_to_string("/proc/stat")
+        .ok()?
+        .lines()
+        .find_map(|l| l.strip_prefix("btime ")?.trim().parse().ok())?;
+    let ticks_per_sec = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks_per_sec <= 0 {
+        return None;
+    }
+    Some(boot_time + start_ticks / ticks_per_sec as u64)
+}
+
+#[cfg(target_os = "macos")]
+fn bsd_info(pid: u32) -> Option<libc::proc_bsdinfo> {
+    unsafe {
+        let mut info: libc::proc_bsdinfo = std::mem::zeroed();
+        let size = std::mem::size_of::<libc::proc_bsdinfo>() as libc::c_int;
+        let n = libc::proc_pidinfo(
+            pid as libc::c_int,
+            libc::PROC_PIDTBSDINFO,
+            0,
+            &mut info as *mut libc::proc_bsdinfo as *mut libc::c_void,
+            size,
+        );
+        if n == size { Some(info) } else { None }
+    }
+}
+
+/// 프로세스 이름 (실행 파일명, 경로 제외)
+#[cfg(target_os = "macos")]
+pub fn process_name(pid: u32) -> Option<String> {
+    let info = bsd_info(pid)?;
+    // pbi_name 은 MAXCOMLEN(16) 보다 긴 이름을 담을 수 있음 — 비어 있으면 pbi_comm 사용
+    let to_string = |raw: &[libc::c_char]| {
+        let bytes: Vec<u8> = raw.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).to_string()
+    };
+    let name = to_string(&info.pbi_name);
+    let name = if name.is_empty() { to_string(&info.pbi_comm) } else { name };
+    if name.is_empty() { None } else { Some(name) }
+}
+
+/// 프로세스 시작 시각 (Unix epoch 초)
+#[cfg(target_os = "macos")]
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    bsd_info(pid).map(|info| info.pbi_start_tvsec)
+}
+
+/// PID 재사용 판별용 시작 토큰 — 같은 PID 라도 다른 프로세스면 값이 달라진다
+///
+/// Linux: 부팅 이후 starttime (clock tick, 초 단위보다 정밀)
+#[cfg(target_os = "linux")]
+pub fn start_token(pid: u32) -> Option<String> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    let after_comm = &stat[stat.rfind(')')? + 1..];
+    after_comm.split_whitespace().nth(19).map(|s| s.to_string())
+}
+
+/// PID 재사용 판별용 시작 토큰 — 같은 PID 라도 다른 프로세스면 값이 달라진다
+///
+/// macOS: 시작 시각 (초.마이크로초)
+#[cfg(target_os = "macos")]
+pub fn start_token(pid: u32) -> Option<String> {
+    bsd_info(pid).map(|info| format!("{}.{:06}", info.pbi_start_tvsec, info.pbi_start_tvusec))
+}
+
+/// 전체 커맨드라인 (인자 포함, 공백으로 연결)
+#[cfg(target_os = "linux")]
+pub fn command_line(pid: u32) -> Option<String> {
+    let raw = std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    let args: Vec<String> = raw
+        .split(|&b| b == 0)
+        .filter(|a| !a.is_empty())
+        .map(|a| String::from_utf8_lossy(a).to_string())
+        .collect();
+    if args.is_empty() { None } else { Some(args.join(" ")) }
+}
+
+/// 전체 커맨드라인 (인자 포함, 공백으로 연결)
+///
+/// `KERN_PROCARGS2` 레이아웃: argc(int) → 실행 경로 → NUL 패딩 → argv[0..argc] → 환경변수
+#[cfg(target_os = "macos")]
+pub fn command_line(pid: u32) -> Option<String> {
+    let buf = unsafe {
+        let mut mib = [libc::CTL_KERN, libc::KERN_PROCARGS2, pid as libc::c_int];
+        let mut size: libc::size_t = 0;
+        if libc::sysctl(mib.as_mut_ptr(), 3, std::ptr::null_mut(), &mut size, std::ptr::null_mut(), 0) != 0 {
+            return None;
+        }
+        let mut buf = vec![0u8; size];
+        if libc::sysctl(mib.as_mut_ptr(), 3, buf.as_mut_ptr() as *mut libc::c_void, &mut size, std::ptr::null_mut(), 0) != 0 {
+            return None;
+        }
+        buf.truncate(size);
+        buf
+    };
+    let argc = i32::from_ne_bytes(buf.get(..4)?.try_into().ok()?).max(0) as usize;
+    let rest = &buf[4..];
+    let exec_end = rest.iter().position(|&b| b == 0)?;
+    let args_start = exec_end + rest[exec_end..].iter().take_while(|&&b| b == 0).count();
+    let args: Vec<String> = rest[args_start..]
+        .split(|&b| b == 0)
+        .take(argc)
+        .map(|a| String::from_utf8_lossy(a).to_string())
+        .collect();
+    if args.is_empty() { None } else { Some(args.join(" ")) }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn process_name(_pid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn process_start_time(_pid: u32) -> Option<u64> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn start_token(_pid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn command_line(_pid: u32) -> Option<String> {
+    None
+}
+
+/// 현재 시각 (Unix epoch 초)
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 현재 시각 (Unix epoch 밀리초)
+pub fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// 프로세스가 아직 살아있는지 (좀비는 죽은 것으로 간주)
+#[cfg(target_os = "linux")]
+pub fn is_alive(pid: u32) -> bool {
+    let Ok(stat) = std::fs::read_to_string(format!("/proc/{}/stat", pid)) else { return false };
+    // ')' 뒤 첫 필드가 state — Z(zombie) / X(dead)
+    !matches!(stat.rfind(')').and_then(|i| stat[i + 1..].split_whitespace().next()), Some("Z") | Some("X"))
+}
+
+/// 프로세스가 아직 살아있는지 (signal 0 전송으로 확인)
+#[cfg(all(unix, not(target_os = "linux")))]
+pub fn is_alive(pid: u32) -> bool {
+    let rc = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    // EPERM: 존재하지만 다른 사용자 소유
+    rc == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+pub fn is_alive(_pid: u32) -> bool {
+    true
+}
+
+/// 현재 살아있는 모든 PID
+#[cfg(target_os = "linux")]
+pub fn all_pids() -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir("/proc") else { return Vec::new() };
+    entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str().and_then(|s| s.parse::<u32>().ok()))
+        .collect()
+}
+
+/// 현재 살아있는 모든 PID
+#[cfg(target_os = "macos")]
+pub fn all_pids() -> Vec<u32> {
+    unsafe {
+        let count = libc::proc_listallpids(std::ptr::null_mut(), 0);
+        if count <= 0 {
+            return Vec::new();
+        }
+        // 호출 사이에 프로세스가 늘어날 수 있으므로 여유분 확보
+        let mut pids: Vec<libc::c_int> = vec![0; count as usize + 64];
+        let n = libc::proc_listallpids(
+            pids.as_mut_ptr() as *mut libc::c_void,
+            (pids.len() * std::mem::size_of::<libc::c_int>()) as libc::c_int,
+        );
+        pids.truncate(n.max(0) as usize);
+        pids.into_iter().filter(|&p| p > 0).map(|p| p as u32).collect()
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn all_pids() -> Vec<u32> {
+    Vec::new()
+}
+
+/// 프로세스의 세션 ID (execute_command 가 setsid 로 띄운 서버는 PID == SID)
+#[cfg(unix)]
+pub fn session_id(pid: u32) -> Option<u32> {
+    let sid = unsafe { libc::getsid(pid as libc::pid_t) };
+    if sid > 0 { Some(sid as u32) } else { None }
+}
+
+#[cfg(not(unix))]
+pub fn session_id(_pid: u32) -> Option<u32> {
+    None
+}
+
+/// 세션에 속한 모든 살아있는 PID (세션 리더가 이미 죽었어도 남은 자식들 포함)
+pub fn session_members(sid: u32) -> Vec<u32> {
+    all_pids()
+        .into_iter()
+        .filter(|&pid| session_id(pid) == Some(sid) && is_alive(pid))
+        .collect()
+}
@@ -0,0 +1,255 @@
+//! 실행(run) 단위 로그 구분
+//!
+//! 같은 프로젝트의 모든 실행이 `logs/<id>.log` 하나에 이어 쓰이므로, spawn 마다 헤더를,
+//! 종료 시 푸터를 남기고 `logs/<id>.runs.json` 에 실행 목록을 기록한다.
+//!
+//! ```text
+//! === [run 3] started 2026-10-18T03:12:45.120Z pid 4242 ===
+//!     command: bun run dev
+//!     cwd: /Users/me/project
+//!     PATH: /Users/me/.bun/bin:/usr/local/bin:...
+//! ... 서버 출력 ...
+//! === [run 3] exited code 0 after 12.3s ===
+//! ```
+//!
+//! 헤더는 spawn 직후, 출력 reader 스레드(`capture.rs`)를 시작하기 전에 쓰므로 서버 출력보다 항상 앞에 온다.
+//! 마커는 stamped 로그에도 `run` 태그로 남는다.
+//! 특정 실행의 로그는 헤더/푸터 마커로 찾는다 (rotation 된 `.log.1`, `.log.N.gz` 까지 포함).
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::capture::{self, Stream};
+use crate::{logs, persist, procinfo};
+
+/// runs.json 에 보관할 최근 실행 수
+const MAX_RUNS: usize = 50;
+/// `read` 기본 한도
+pub const DEFAULT_RUN_BYTES: usize = 1024 * 1024;
+const MARKER_PREFIX: &str = "=== [run ";
+
+/// runs.json read-modify-write 직렬화 (동시에 여러 프로젝트가 재시작될 수 있음)
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunRecord {
+    pub run: u64,
+    pub pid: Option<u32>,
+    pub command: String,
+    pub cwd: Option<String>,
+    pub started_at: u64,
+    #[serde(default)]
+    pub exited_at: Option<u64>,
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    #[serde(default)]
+    pub signal: Option<i32>,
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// `read_log_run` 응답
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunLog {
+    pub record: Option<RunRecord>,
+    pub content: String,
+    /// 한도를 넘어 앞부분을 잘라냄
+    pub clipped: bool,
+}
+
+/// spawn 직전에 할당한 실행 정보 — 헤더는 PID 를 알게 된 뒤 `started` 에서 기록
+pub struct RunStart {
+    pub run: u64,
+    header_prefix: String,
+    header_suffix: String,
+}
+
+fn index_path(log_file: &Path) -> PathBuf {
+    log_file.with_extension("runs.json")
+}
+
+fn read_index(log_file: &Path) -> Vec<RunRecord> {
+    persist::read_json(&index_path(log_file)).ok().flatten().unwrap_or_default()
+}
+
+fn update_index(log_file: &Path, f: impl FnOnce(&mut Vec<RunRecord>)) {
+    let _guard = INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut runs = read_index(log_file);
+    f(&mut runs);
+    let excess = runs.len().saturating_sub(MAX_RUNS);
+    runs.drain(..excess);
+    if let Err(e) = persist::write_json(&index_path(log_file), &runs) {
+        println!("[Runs] Failed to write run index: {}", e);
+    }
+}
+
+/// `2026-10-18T03:12:45.120Z` (UTC, epoch 밀리초 기준)
+pub fn utc_timestamp(millis: u64) -> String {
+    let secs = millis / 1000;
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    // civil_from_days (Howard Hinnant)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60,
+        millis % 1000
+    )
+}
+
+/// 새 실행 번호 할당 + runs.json 기록, 헤더 준비
+pub fn begin(log_file: &Path, command: &str, cwd: Option<&str>, path_env: &str) -> RunStart {
+    let started_millis = procinfo::now_millis();
+    let started_at = started_millis / 1000;
+    let mut run = 1;
+    update_index(log_file, |runs| {
+        run = runs.last().map_or(1, |r| r.run + 1);
+        runs.push(RunRecord {
+            run,
+            pid: None,
+            command: command.to_string(),
+            cwd: cwd.map(str::to_string),
+            started_at,
+            exited_at: None,
+            exit_code: None,
+            signal: None,
+            duration_ms: None,
+        });
+    });
+
+    let header_prefix = format!("{}{}] started {} pid ", MARKER_PREFIX, run, utc_timestamp(started_millis));
+    let mut suffix = String::from(" ===\n");
+    suffix.push_str(&format!("    command: {}\n", command));
+    if let Some(cwd) = cwd {
+        suffix.push_str(&format!("    cwd: {}\n", cwd));
+    }
+    suffix.push_str(&format!("    PATH: {}\n", path_env));
+    RunStart { run, header_prefix, header_suffix: suffix }
+}
+
+/// PID 기록 + 헤더 쓰기 — reader 스레드 시작 전에 호출
+pub fn started(log_file: &Path, start: &RunStart, pid: u32) {
+    append(log_file, &format!("{}{}{}", start.header_prefix, pid, start.header_suffix));
+    update_index(log_file, |runs| {
+        if let Some(r) = runs.iter_mut().find(|r| r.run == start.run) {
+            r.pid = Some(pid);
+        }
+    });
+}
+
+/// 합친 로그 + stamped 로그에 마커 기록 — 이전 출력이 줄바꿈 없이 끝났어도 마커는 새 줄에서 시작
+fn append(log_file: &Path, text: &str) {
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(log_file) {
+        let _ = file.write_all(format!("\n{}", text).as_bytes());
+    }
+    capture::append_stamped(log_file, Stream::Run, text);
+}
+
+/// 종료 푸터 기록 + runs.json 갱신
+pub fn finish(log_file: &Path, run: u64, exit_code: Option<i32>, signal: Option<i32>, duration: Duration) {
+    let status = match (exit_code, signal) {
+        (Some(code), _) => format!("code {}", code),
+        (None, Some(sig)) => format!("signal {}", sig),
+        (None, None) => "unknown status".to_string(),
+    };
+    append(log_file, &format!("{}{}] exited {} after {:.1}s ===\n", MARKER_PREFIX, run, status, duration.as_secs_f64()));
+    update_index(log_file, |runs| {
+        if let Some(r) = runs.iter_mut().find(|r| r.run == run) {
+            r.exited_at = Some(procinfo::now_secs());
+            r.exit_code = exit_code;
+            r.signal = signal;
+            r.duration_ms = Some(duration.as_millis() as u64);
+        }
+    });
+}
+
+/// 로그를 보관본까지 지울 때 실행 목록도 삭제
+pub fn clear(log_file: &Path) {
+    let _guard = INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _ = fs::remove_file(index_path(log_file));
+}
+
+/// 최신순 실행 목록
+pub fn list(log_file: &Path) -> Vec<RunRecord> {
+    let mut runs = read_index(log_file);
+    runs.reverse();
+    runs
+}
+
+/// 마커 줄이면 실행 번호
+fn marker_run(line: &str) -> Option<u64> {
+    line.strip_prefix(MARKER_PREFIX)?.split(']').next()?.parse().ok()
+}
+
+/// run 헤더 줄인지 (새 실행 시작)
+pub fn is_run_start(line: &str) -> bool {
+    marker_run(line).is_some() && line.contains("] started ")
+}
+
+/// 끝 `max_bytes` 만 남기기 — 잘린 첫 줄은 버림
+fn clip_front(content: &mut String, max_bytes: usize) -> bool {
+    if content.len() <= max_bytes {
+        return false;
+    }
+    let cut = content.len() - max_bytes;
+    let cut = content.as_bytes()[cut..].iter().position(|&b| b == b'\n').map_or(content.len(), |i| cut + i + 1);
+    content.drain(..cut);
+    true
+}
+
+/// 특정 실행의 로그 (헤더 ~ 푸터) — 오래된 보관 로그부터 현재 로그까지 순서대로 찾음
+///
+/// `max_bytes` 를 넘으면 끝부분만 남긴다.
+pub fn read(log_file: &Path, run: u64, max_bytes: usize) -> Result<RunLog, String> {
+    let record = read_index(log_file).into_iter().find(|r| r.run == run);
+    let mut content = String::new();
+    let mut clipped = false;
+    let mut inside = false;
+    'files: for file in logs::chronological(log_file) {
+        let Ok(reader) = logs::open_reader(&file) else { continue };
+        for line in BufReader::new(reader).split(b'\n') {
+            let line = line.map_err(|e| format!("Failed to read {:?}: {}", file, e))?;
+            let line = String::from_utf8_lossy(&line);
+            if let Some(marker) = marker_run(&line) {
+                let is_footer = marker == run && inside;
+                inside = marker == run;
+                if is_footer && line.contains("] exited ") {
+                    content.push_str(&line);
+                    content.push('\n');
+                    break 'files;
+                }
+            }
+            if inside {
+                content.push_str(&line);
+                content.push('\n');
+                if content.len() > max_bytes * 2 {
+                    clipped |= clip_front(&mut content, max_bytes);
+                }
+            }
+        }
+    }
+    clipped |= clip_front(&mut content, max_bytes);
+    if record.is_none() && content.is_empty() {
+        return Err(format!("Run {} not found", run));
+    }
+    Ok(RunLog { record, content, clipped })
+}
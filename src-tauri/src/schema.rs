@@ -0,0 +1,207 @@
+//! ports.json 스키마 버전 + 마이그레이션
+//!
+//! | version | 형식                                                                    |
+//! |---------|-------------------------------------------------------------------------|
+//! | 1       | `PortInfo` 배열 그대로, `worktreePath` 는 콤마 구분 문자열              |
+//! | 2       | `{ "schemaVersion": 2, "ports": [...] }`, `worktreePaths` 는 문자열 배열 |
+//!
+//! 프론트엔드와 주고받는 `PortInfo` 는 지금처럼 `worktreePath` 문자열을 쓰고,
+//! 디스크에 쓸 때만 배열로 변환한다. 구버전 파일을 올릴 때는 원본을
+//! `ports.json.v<N>.bak` 으로 남겨 이전 버전 앱으로 되돌릴 수 있게 한다.
+
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use serde_json::{json, Map, Value};
+
+use crate::{persist, PortInfo};
+
+pub const CURRENT_VERSION: u64 = 2;
+
+type Migration = fn(Value) -> Result<Value, String>;
+
+/// `MIGRATIONS[i]` 는 버전 `i + 1` → `i + 2`
+const MIGRATIONS: &[Migration] = &[v1_to_v2];
+
+fn version_of(value: &Value) -> u64 {
+    match value {
+        Value::Object(obj) => obj.get("schemaVersion").and_then(Value::as_u64).unwrap_or(1),
+        _ => 1,
+    }
+}
+
+/// 현재 버전으로 올린 값과 원래 버전
+pub fn migrate(mut value: Value) -> Result<(Value, u64), String> {
+    let from = version_of(&value);
+    if from > CURRENT_VERSION {
+        return Err(format!(
+            "ports.json schema version {} is newer than this app supports ({}) — please update the app",
+            from, CURRENT_VERSION
+        ));
+    }
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(from.saturating_sub(1) as usize) {
+        value = migration(value).map_err(|e| format!("Migration v{} → v{} failed: {}", i + 1, i + 2, e))?;
+    }
+    Ok((value, from))
+}
+
+fn split_worktrees(raw: &str) -> Vec<Value> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| Value::String(s.to_string()))
+        .collect()
+}
+
+/// v1 → v2: 배열을 envelope 로 감싸고 `worktreePath` 문자열을 `worktreePaths` 배열로 분리
+fn v1_to_v2(value: Value) -> Result<Value, String> {
+    let Value::Array(ports) = value else { return Err("expected an array of ports".to_string()) };
+    let ports: Vec<Value> = ports
+        .into_iter()
+        .map(|mut entry| {
+            if let Some(obj) = entry.as_object_mut() {
+                let paths = match obj.remove("worktreePath") {
+                    Some(Value::String(raw)) => split_worktrees(&raw),
+                    Some(Value::Array(items)) => items,
+                    _ => Vec::new(),
+                };
+                obj.insert("worktreePaths".to_string(), Value::Array(paths));
+            }
+            entry
+        })
+        .collect();
+    Ok(json!({ "schemaVersion": 2, "ports": ports }))
+}
+
+/// 디스크 항목 → PortInfo JSON (`worktreePaths` → `worktreePath` 문자열)
+fn from_disk(mut entry: Value) -> Value {
+    if let Some(obj) = entry.as_object_mut() {
+        let joined = match obj.remove("worktreePaths") {
+            Some(Value::Array(items)) => {
+                let paths: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+                if paths.is_empty() { Value::Null } else { Value::String(paths.join(", ")) }
+            }
+            _ => Value::Null,
+        };
+        obj.insert("worktreePath".to_string(), joined);
+    }
+    entry
+}
+
+/// PortInfo JSON → 디스크 항목 (`worktreePath` 문자열 → `worktreePaths`)
+fn to_disk(mut entry: Value) -> Value {
+    if let Some(obj) = entry.as_object_mut() {
+        let paths = match obj.remove("worktreePath") {
+            Some(Value::String(raw)) => split_worktrees(&raw),
+            _ => Vec::new(),
+        };
+        obj.insert("worktreePaths".to_string(), Value::Array(paths));
+    }
+    entry
+}
+
+/// 임의 버전의 ports JSON (파일 / import) → PortInfo 목록
+pub fn ports_from_value(value: Value) -> Result<Vec<PortInfo>, String> {
+    let (mut value, _) = migrate(value)?;
+    let entries = match value.get_mut("ports").map(Value::take) {
+        Some(Value::Array(entries)) => entries,
+        _ => return Err("ports.json has no ports array".to_string()),
+    };
+    entries
+        .into_iter()
+        .map(|entry| serde_json::from_value(from_disk(entry)).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// ports.json 읽기 — 구버전이면 원본을 `.v<N>.bak` 으로 남기고 현재 버전으로 다시 저장
+pub fn read_ports(path: &Path) -> Result<Option<Vec<PortInfo>>, String> {
+    let Some(raw) = persist::read_json::<Value>(path)? else { return Ok(None) };
+    let from = version_of(&raw);
+    let ports = ports_from_value(raw)?;
+
+    if from < CURRENT_VERSION {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".v{}.bak", from));
+        let backup = path.with_file_name(name);
+        // 처음 올릴 때의 원본만 보관 (이전 버전 앱으로 되돌릴 때 사용)
+        if !backup.exists() && path.exists() {
+            if let Err(e) = fs::copy(path, &backup) {
+                println!("[Schema] Failed to back up {:?}: {}", path, e);
+            }
+        }
+        println!("[Schema] Upgrading {:?} from v{} to v{}", path, from, CURRENT_VERSION);
+        write_ports(path, &ports)?;
+    }
+    Ok(Some(ports))
+}
+
+/// ports.json 쓰기 직렬화 (save_ports / 마이그레이션 등 여러 스레드에서 저장)
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+/// `write_ports` 마다 증가 — `PortsCache` 무효화용
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 백그라운드 루프(watcher / health)용 포트 목록 캐시
+///
+/// 매 tick 마다 ports.json 을 다시 파싱하지 않고, `write_ports` 로 저장됐거나
+/// 파일의 수정 시각 / 크기가 바뀐 뒤에만 다시 읽는다 — api-server.ts 의 `savePortsData`,
+/// AI 프롬프트 편집, 웹 API 를 통한 Supabase 가져오기 등 앱 밖에서 다시 쓴 경우 포함.
+#[derive(Default)]
+pub struct PortsCache {
+    cached: Mutex<Option<(CacheKey, Vec<PortInfo>)>>,
+}
+
+/// (`GENERATION`, 수정 시각, 크기) — 파일이 없으면 `None`
+type CacheKey = (u64, Option<(SystemTime, u64)>);
+
+impl PortsCache {
+    pub fn get(&self, path: &Path) -> Result<Vec<PortInfo>, String> {
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        // 읽는 도중 저장되면 다음 호출에서 다시 읽도록 읽기 전의 세대 / 파일 상태를 기록
+        let generation = GENERATION.load(Ordering::Acquire);
+        let stat = fs::metadata(path).ok().and_then(|m| Some((m.modified().ok()?, m.len())));
+        let key = (generation, stat);
+        if let Some((loaded, ports)) = cached.as_ref() {
+            if *loaded == key {
+                return Ok(ports.clone());
+            }
+        }
+        let ports = read_ports(path)?.unwrap_or_default();
+        *cached = Some((key, ports.clone()));
+        Ok(ports)
+    }
+}
+
+/// 현재 스키마 버전으로 ports.json 저장
+///
+/// 디스크의 파일이 이 앱보다 새 버전이면 덮어쓰지 않는다 (이전 버전 앱으로 실행했을 때 데이터 보호).
+pub fn write_ports(path: &Path, ports: &[PortInfo]) -> Result<(), String> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if let Ok(content) = fs::read_to_string(path) {
+        if let Ok(existing) = serde_json::from_str::<Value>(&content) {
+            let version = version_of(&existing);
+            if version > CURRENT_VERSION {
+                return Err(format!(
+                    "ports.json was written by a newer app (schema v{}) — refusing to overwrite with v{}",
+                    version, CURRENT_VERSION
+                ));
+            }
+        }
+    }
+    let result = persist::write_json(path, &to_document(ports)?);
+    GENERATION.fetch_add(1, Ordering::Release);
+    result
+}
+
+/// 현재 스키마 버전의 디스크 형식 (`{ schemaVersion, ports }`) — 내보내기에서도 사용
+pub fn to_document(ports: &[PortInfo]) -> Result<Value, String> {
+    let entries: Vec<Value> = ports
+        .iter()
+        .map(|p| serde_json::to_value(p).map(to_disk).map_err(|e| e.to_string()))
+        .collect::<Result<_, _>>()?;
+    let mut doc = Map::new();
+    doc.insert("schemaVersion".to_string(), json!(CURRENT_VERSION));
+    doc.insert("ports".to_string(), Value::Array(entries));
+    Ok(Value::Object(doc))
+}
@@ -0,0 +1,201 @@
+//! 서버 종료 — 단일 PID 또는 execute_command 가 만든 세션(setsid) 전체
+//!
+//! 서버는 `bash` 세션 리더 아래에서 bundler watcher, esbuild 서비스, `tsc --watch` 같은
+//! 자식 프로세스를 띄운다. 포트 소유자만 죽이면 이들이 남으므로 세션 전체에
+//! 종료 시그널 → 대기 → SIGKILL 을 보낸다.
+//!
+//! PortInfo 의 `stopSignal` / `stopTimeoutMs` / `stopCommand` 로 프로젝트별 조정 가능
+//! (DB 연결을 정리해야 하는 서버, `docker compose down` 이 필요한 스택 등).
+
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use std::process::{Command, Stdio};
+#[cfg(unix)]
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+#[cfg(unix)]
+use crate::procinfo;
+
+/// 기본 종료 대기 시간 (종료 시그널 후 SIGKILL 까지)
+#[cfg(unix)]
+pub const DEFAULT_GRACE: Duration = Duration::from_millis(200);
+
+/// stopCommand 최대 실행 시간 — 넘기면 강제 종료하고 시그널 단계로 진행
+#[cfg(unix)]
+pub const STOP_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[cfg(unix)]
+const POLL: Duration = Duration::from_millis(50);
+
+/// 종료 시 처음 보낼 시그널 (grace 이후에는 항상 SIGKILL)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum StopSignal {
+    #[default]
+    #[serde(rename = "TERM", alias = "SIGTERM")]
+    Term,
+    #[serde(rename = "INT", alias = "SIGINT")]
+    Int,
+    #[serde(rename = "HUP", alias = "SIGHUP")]
+    Hup,
+    #[serde(rename = "QUIT", alias = "SIGQUIT")]
+    Quit,
+}
+
+impl StopSignal {
+    #[cfg(unix)]
+    fn as_libc(self) -> libc::c_int {
+        match self {
+            StopSignal::Term => libc::SIGTERM,
+            StopSignal::Int => libc::SIGINT,
+            StopSignal::Hup => libc::SIGHUP,
+            StopSignal::Quit => libc::SIGQUIT,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn send(pid: u32, signal: libc::c_int) -> bool {
+    unsafe { libc::kill(pid as libc::pid_t, signal) == 0 }
+}
+
+/// 주어진 PID 들이 모두 죽을 때까지 최대 `grace` 동안 대기 — 남은 PID 반환
+#[cfg(unix)]
+fn wait_for_exit(pids: &[u32], grace: Duration) -> Vec<u32> {
+    let deadline = Instant::now() + grace;
+    loop {
+        let alive: Vec<u32> = pids.iter().copied().filter(|&p| procinfo::is_alive(p)).collect();
+        if alive.is_empty() || Instant::now() >= deadline {
+            return alive;
+        }
+        std::thread::sleep(POLL);
+    }
+}
+
+/// 단일 프로세스 종료: `signal` → `grace` 대기 → 아직 살아있으면 SIGKILL
+#[cfg(unix)]
+pub fn terminate_pid(pid: u32, signal: StopSignal, grace: Duration) {
+    println!("[Shutdown] Killing PID: {} ({:?})", pid, signal);
+    if !send(pid, signal.as_libc()) {
+        // 종료 시그널 실패하면 바로 SIGKILL
+        println!("[Shutdown] {:?} failed, sending SIGKILL to PID: {}", signal, pid);
+        send(pid, libc::SIGKILL);
+        return;
+    }
+    if !wait_for_exit(&[pid], grace).is_empty() {
+        println!("[Shutdown] Process still alive, sending SIGKILL to PID: {}", pid);
+        send(pid, libc::SIGKILL);
+    }
+}
+
+/// 세션 전체 종료: 모든 멤버에 `signal` → `grace` 대기 → 남은 멤버(그 사이 fork 된 것 포함)에 SIGKILL
+///
+/// 종료 신호를 보낸 모든 PID 를 반환한다.
+#[cfg(unix)]
+pub fn terminate_session(sid: u32, signal: StopSignal, grace: Duration) -> Vec<u32> {
+    let mut members = procinfo::session_members(sid);
+    if members.is_empty() {
+        return members;
+    }
+    println!("[Shutdown] Terminating session {} with {:?} ({} processes): {:?}", sid, signal, members.len(), members);
+
+    for &pid in &members {
+        send(pid, signal.as_libc());
+    }
+    let mut remaining = wait_for_exit(&members, grace);
+
+    for pid in procinfo::session_members(sid) {
+        if !members.contains(&pid) {
+            members.push(pid);
+            remaining.push(pid);
+        }
+    }
+    if !remaining.is_empty() {
+        println!("[Shutdown] Session {} still has {} processes, sending SIGKILL: {:?}", sid, remaining.len(), remaining);
+        for &pid in &remaining {
+            send(pid, libc::SIGKILL);
+        }
+    }
+    members
+}
+
+/// 이 프로젝트가 띄운 세션 목록
+///
+/// - `launched`: 추적 중이거나 Supervisor 가 마지막으로 띄운 PID (setsid 로 띄웠으므로 PID == SID)
+/// - 살아있는 세션 리더는 그대로 세션으로 인정
+/// - 이미 죽은 리더는 포트 소유 프로세스가 그 세션에 남아있을 때만 인정 (PID 재사용 오인 방지)
+#[cfg(unix)]
+pub fn owned_sessions(launched: &[u32], port_pids: &[u32]) -> Vec<u32> {
+    let port_sids: Vec<u32> = port_pids.iter().filter_map(|&p| procinfo::session_id(p)).collect();
+    let mut sessions = Vec::new();
+    for &pid in launched {
+        let leader_alive = procinfo::is_alive(pid) && procinfo::session_id(pid) == Some(pid);
+        if (leader_alive || port_sids.contains(&pid)) && !sessions.contains(&pid) {
+            sessions.push(pid);
+        }
+    }
+    sessions
+}
+
+/// 세션 전체 즉시 SIGKILL (force restart 용) — 종료한 PID 반환
+#[cfg(unix)]
+pub fn kill_session(sid: u32) -> Vec<u32> {
+    let members = procinfo::session_members(sid);
+    for &pid in &members {
+        send(pid, libc::SIGKILL);
+    }
+    members
+}
+
+/// 시그널 전에 사용자 정의 stopCommand 실행 (예: `docker compose down`)
+///
+/// `folder_path` 를 cwd 로, 출력은 프로젝트 로그 파일에 append.
+/// `timeout` 안에 끝나지 않으면 stopCommand 세션을 종료하고 에러 반환 — 호출부는 시그널 단계로 진행한다.
+#[cfg(unix)]
+pub fn run_stop_command(command: &str, folder_path: Option<&str>, log_file: Option<&Path>, timeout: Duration) -> Result<(), String> {
+    println!("[Shutdown] Running stop command: {}", command);
+    let mut cmd = Command::new("bash");
+    cmd.arg("-c")
+        .arg(command)
+        .env("PATH", crate::supervisor::shell_path())
+        .stdin(Stdio::null());
+    if let Some(fp) = folder_path.filter(|fp| !fp.is_empty()) {
+        cmd.current_dir(fp);
+    }
+    match log_file.and_then(|p| std::fs::OpenOptions::new().create(true).append(true).open(p).ok()) {
+        Some(log) => {
+            let log_err = log.try_clone().map_err(|e| e.to_string())?;
+            cmd.stdout(log).stderr(log_err);
+        }
+        None => {
+            cmd.stdout(Stdio::null()).stderr(Stdio::null());
+        }
+    }
+    {
+        use std::os::unix::process::CommandExt;
+        unsafe {
+            cmd.pre_exec(|| {
+                // 타임아웃 시 stop command 가 띄운 자식까지 함께 정리하기 위해 별도 세션
+                libc::setsid();
+                Ok(())
+            });
+        }
+    }
+
+    let mut child = cmd.spawn().map_err(|e| format!("Failed to run stop command: {}", e))?;
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return Ok(()),
+            Ok(Some(status)) => return Err(format!("Stop command exited with {}", status)),
+            Ok(None) if Instant::now() >= deadline => {
+                kill_session(child.id());
+                let _ = child.wait();
+                return Err(format!("Stop command timed out after {:?}", timeout));
+            }
+            Ok(None) => std::thread::sleep(POLL),
+            Err(e) => return Err(format!("Failed to wait for stop command: {}", e)),
+        }
+    }
+}
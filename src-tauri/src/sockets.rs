@@ -0,0 +1,443 @@
+//! 포트 → LISTEN 소켓 소유 프로세스 조회 (lsof 없이 동작하는 네이티브 백엔드)
+//!
+//! - Linux: `/proc/net/{tcp,tcp6,udp,udp6}` 에서 LISTEN(UDP 는 바인딩된 미연결) 소켓의 inode 를 찾고,
+//!   `/proc/<pid>/fd/*` 의 `socket:[inode]` 링크로 PID 를 역추적한다.
+//! - macOS: libproc (`proc_listallpids` → `proc_pidinfo(PROC_PIDLISTFDS)` → `proc_pidfdinfo`) 로
+//!   프로세스별 소켓을 직접 조회한다.
+//! - 그 외 플랫폼: 빈 결과 (호출부가 추적 중인 PID 로 폴백)
+//!
+//! 프로세스를 spawn 하지 않으므로 GUI 앱 PATH 에 lsof 가 없어도 동작하고,
+//! 여러 포트를 한 번의 스캔으로 해석할 수 있다 (`listeners_by_port`).
+
+use std::collections::HashMap;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Family {
+    Ipv4,
+    Ipv6,
+}
+
+/// 포트를 점유 중인 소켓 하나 (TCP LISTEN 또는 바인딩된 UDP)
+#[derive(Debug, Clone, Serialize)]
+pub struct Listener {
+    pub pid: u32,
+    pub port: u16,
+    /// 바인딩 주소 (`0.0.0.0`, `127.0.0.1`, `::` 등)
+    pub address: String,
+    pub family: Family,
+    pub protocol: Protocol,
+}
+
+/// 해당 포트의 모든 리스너
+pub fn listeners(port: u16) -> Vec<Listener> {
+    scan(&|p| p == port)
+}
+
+/// 여러 포트를 한 번의 스캔으로 해석 — 리스너가 없는 포트도 빈 Vec 으로 포함
+pub fn listeners_by_port(ports: &[u16]) -> HashMap<u16, Vec<Listener>> {
+    let mut map: HashMap<u16, Vec<Listener>> = ports.iter().map(|&p| (p, Vec::new())).collect();
+    for listener in scan(&|p| ports.contains(&p)) {
+        if let Some(list) = map.get_mut(&listener.port) {
+            list.push(listener);
+        }
+    }
+    map
+}
+
+/// 해당 포트에서 LISTEN 중인 모든 PID (IPv4/IPv6, TCP/UDP 통합, 중복 제거)
+pub fn listening_pids(port: u16) -> Vec<u32> {
+    unique_pids(&listeners(port))
+}
+
+/// 리스너 목록에서 PID 만 발견 순서대로 중복 없이 추출
+pub fn unique_pids(listeners: &[Listener]) -> Vec<u32> {
+    let mut pids = Vec::new();
+    for l in listeners {
+        if !pids.contains(&l.pid) {
+            pids.push(l.pid);
+        }
+    }
+    pids
+}
+
+#[cfg(target_os = "linux")]
+fn scan(wanted: &dyn Fn(u16) -> bool) -> Vec<Listener> {
+    linux::scan(wanted)
+}
+
+#[cfg(target_os = "macos")]
+fn scan(wanted: &dyn Fn(u16) -> bool) -> Vec<Listener> {
+    darwin::scan(wanted)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn scan(_wanted: &dyn Fn(u16) -> bool) -> Vec<Listener> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::{Family, Listener, Protocol};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    /// `/proc/net/tcp*` 의 st 컬럼 값 — 0A = TCP_LISTEN
+    const TCP_LISTEN: &str = "0A";
+    /// `/proc/net/udp*` 에서 바인딩만 된 (connect 하지 않은) 소켓은 TCP_CLOSE(07) 로 표시됨
+    const UDP_UNCONNECTED: &str = "07";
+
+    const TABLES: [(&str, Protocol, Family, &str); 4] = [
+        ("/proc/net/tcp", Protocol::Tcp, Family::Ipv4, TCP_LISTEN),
+        ("/proc/net/tcp6", Protocol::Tcp, Family::Ipv6, TCP_LISTEN),
+        ("/proc/net/udp", Protocol::Udp, Family::Ipv4, UDP_UNCONNECTED),
+        ("/proc/net/udp6", Protocol::Udp, Family::Ipv6, UDP_UNCONNECTED),
+    ];
+
+    /// 소켓 테이블 한 행에서 뽑아낸 값 (PID 매핑 전)
+    struct RawSocket {
+        inode: u64,
+        port: u16,
+        address: String,
+        family: Family,
+        protocol: Protocol,
+    }
+
+    pub(super) fn scan(wanted: &dyn Fn(u16) -> bool) -> Vec<Listener> {
+        let mut sockets = Vec::new();
+        for (table, protocol, family, state) in TABLES {
+            if let Ok(content) = fs::read_to_string(table) {
+                sockets.extend(parse_table(&content, protocol, family, state, wanted));
+            }
+        }
+        if sockets.is_empty() {
+            return Vec::new();
+        }
+
+        let inode_pids = pids_by_inode(&sockets.iter().map(|s| s.inode).collect::<Vec<_>>());
+        let mut result = Vec::new();
+        for s in sockets {
+            for &pid in inode_pids.get(&s.inode).map(|v| v.as_slice()).unwrap_or(&[]) {
+                result.push(Listener {
+                    pid,
+                    port: s.port,
+                    address: s.address.clone(),
+                    family: s.family,
+                    protocol: s.protocol,
+                });
+            }
+        }
+        result
+    }
+
+    /// `/proc/net/tcp` 형식 테이블에서 원하는 포트의 소켓 추출
+    ///
+    /// 각 행: `sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode ...`
+    /// local_address 는 `HEXADDR:HEXPORT` 형식 (주소는 32bit 워드 단위 host byte order)
+    fn parse_table(
+        content: &str,
+        protocol: Protocol,
+        family: Family,
+        state: &str,
+        wanted: &dyn Fn(u16) -> bool,
+    ) -> Vec<RawSocket> {
+        content
+            .lines()
+            .skip(1) // 헤더
+            .filter_map(|line| {
+                let cols: Vec<&str> = line.split_whitespace().collect();
+                if cols.len() < 10 || cols[3] != state {
+                    return None;
+                }
+                let (addr_hex, port_hex) = cols[1].rsplit_once(':')?;
+                let port = u16::from_str_radix(port_hex, 16).ok()?;
+                if port == 0 || !wanted(port) {
+                    return None;
+                }
+                let inode = cols[9].parse::<u64>().ok().filter(|&inode| inode != 0)?;
+                Some(RawSocket { inode, port, address: decode_address(addr_hex, family)?, family, protocol })
+            })
+            .collect()
+    }
+
+    fn decode_address(hex: &str, family: Family) -> Option<String> {
+        let words: Vec<[u8; 4]> = (0..hex.len() / 8)
+            .map(|i| u32::from_str_radix(&hex[i * 8..i * 8 + 8], 16).map(|w| w.to_ne_bytes()))
+            .collect::<Result<_, _>>()
+            .ok()?;
+        match (family, words.len()) {
+            (Family::Ipv4, 1) => Some(Ipv4Addr::from(words[0]).to_string()),
+            (Family::Ipv6, 4) => {
+                let mut bytes = [0u8; 16];
+                for (i, w) in words.iter().enumerate() {
+                    bytes[i * 4..i * 4 + 4].copy_from_slice(w);
+                }
+                Some(Ipv6Addr::from(bytes).to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// `/proc/<pid>/fd` 를 한 번 순회하여 주어진 소켓 inode 들의 소유 PID 수집
+    /// (권한 없는 다른 사용자의 프로세스는 조용히 건너뜀)
+    fn pids_by_inode(inodes: &[u64]) -> HashMap<u64, Vec<u32>> {
+        let mut map: HashMap<u64, Vec<u32>> = HashMap::new();
+        let Ok(entries) = fs::read_dir("/proc") else { return map };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok()) else { continue };
+            let Ok(fds) = fs::read_dir(entry.path().join("fd")) else { continue };
+            for fd in fds.flatten() {
+                let Some(inode) = fs::read_link(fd.path()).ok().and_then(|target| {
+                    let target = target.to_string_lossy().to_string();
+                    target.strip_prefix("socket:[")?.strip_suffix(']')?.parse::<u64>().ok()
+                }) else { continue };
+                if inodes.contains(&inode) {
+                    let pids = map.entry(inode).or_default();
+                    if !pids.contains(&pid) {
+                        pids.push(pid);
+                    }
+                }
+            }
+        }
+        map
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod darwin {
+    //! `<sys/proc_info.h>` 의 socket_fdinfo 레이아웃 미러 (libc 크레이트에 없는 부분만 정의)
+    #![allow(dead_code)] // 커널 구조체 레이아웃 유지용 필드
+
+    use super::{Family, Listener, Protocol};
+    use std::mem;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    const PROC_PIDFDSOCKETINFO: libc::c_int = 3;
+    const SOCKINFO_IN: i32 = 1;
+    const SOCKINFO_TCP: i32 = 2;
+    const TSI_S_LISTEN: i32 = 1;
+    const INI_IPV4: u8 = 0x1;
+    const INI_IPV6: u8 = 0x2;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct ProcFileInfo {
+        fi_openflags: u32,
+        fi_status: u32,
+        fi_offset: i64,
+        fi_type: i32,
+        fi_guardflags: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct VinfoStat {
+        vst_dev: u32,
+        vst_mode: u16,
+        vst_nlink: u16,
+        vst_ino: u64,
+        vst_uid: u32,
+        vst_gid: u32,
+        vst_times: [i64; 8],
+        vst_size: i64,
+        vst_blocks: i64,
+        vst_blksize: i32,
+        vst_flags: u32,
+        vst_gen: u32,
+        vst_rdev: u32,
+        vst_qspare: [i64; 2],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct SockbufInfo {
+        sbi_cc: u32,
+        sbi_hiwat: u32,
+        sbi_mbcnt: u32,
+        sbi_mbmax: u32,
+        sbi_lowat: u32,
+        sbi_flags: i16,
+        sbi_timeo: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct InV6Info {
+        in6_hlim: u8,
+        in6_cksum: i32,
+        in6_ifindex: u16,
+        in6_hops: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct InSockInfo {
+        insi_fport: i32,
+        insi_lport: i32,
+        insi_gencnt: u64,
+        insi_flags: u32,
+        insi_flow: u32,
+        insi_vflag: u8,
+        insi_ip_ttl: u8,
+        rfu_1: u32,
+        /// union { in4in6_addr (pad32[3] + in_addr), in6_addr }
+        insi_faddr: [u8; 16],
+        insi_laddr: [u8; 16],
+        insi_v4_tos: u8,
+        insi_v6: InV6Info,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct TcpSockInfo {
+        tcpsi_ini: InSockInfo,
+        tcpsi_state: i32,
+        tcpsi_timer: [i32; 4],
+        tcpsi_mss: i32,
+        tcpsi_flags: u32,
+        rfu_1: u32,
+        tcpsi_tp: u64,
+    }
+
+    /// soi_proto union — 가장 큰 멤버(un_sockinfo, 526 bytes) 기준으로 패딩
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union SoiProto {
+        pri_in: InSockInfo,
+        pri_tcp: TcpSockInfo,
+        _pad: [u64; 66],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct SocketInfo {
+        soi_stat: VinfoStat,
+        soi_so: u64,
+        soi_pcb: u64,
+        soi_type: i32,
+        soi_protocol: i32,
+        soi_family: i32,
+        soi_options: i16,
+        soi_linger: i16,
+        soi_state: i16,
+        soi_qlen: i16,
+        soi_incqlen: i16,
+        soi_qlimit: i16,
+        soi_timeo: i16,
+        soi_error: u16,
+        soi_oobmark: u32,
+        soi_rcv: SockbufInfo,
+        soi_snd: SockbufInfo,
+        soi_kind: i32,
+        rfu_1: u32,
+        soi_proto: SoiProto,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct SocketFdInfo {
+        pfi: ProcFileInfo,
+        psi: SocketInfo,
+    }
+
+    pub(super) fn scan(wanted: &dyn Fn(u16) -> bool) -> Vec<Listener> {
+        let mut result = Vec::new();
+        for pid in crate::procinfo::all_pids() {
+            let pid = pid as libc::c_int;
+            for fd in socket_fds(pid) {
+                if let Some(listener) = socket_listener(pid, fd, wanted) {
+                    result.push(listener);
+                }
+            }
+        }
+        result
+    }
+
+    fn socket_fds(pid: libc::c_int) -> Vec<libc::c_int> {
+        unsafe {
+            let bytes = libc::proc_pidinfo(pid, libc::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0);
+            if bytes <= 0 {
+                return Vec::new();
+            }
+            let cap = bytes as usize / mem::size_of::<libc::proc_fdinfo>();
+            let mut fds: Vec<libc::proc_fdinfo> = Vec::with_capacity(cap);
+            let bytes = libc::proc_pidinfo(
+                pid,
+                libc::PROC_PIDLISTFDS,
+                0,
+                fds.as_mut_ptr() as *mut libc::c_void,
+                (cap * mem::size_of::<libc::proc_fdinfo>()) as libc::c_int,
+            );
+            if bytes <= 0 {
+                return Vec::new();
+            }
+            fds.set_len(bytes as usize / mem::size_of::<libc::proc_fdinfo>());
+            fds.into_iter()
+                .filter(|f| f.proc_fdtype == libc::PROX_FDTYPE_SOCKET as u32)
+                .map(|f| f.proc_fd)
+                .collect()
+        }
+    }
+
+    fn socket_listener(pid: libc::c_int, fd: libc::c_int, wanted: &dyn Fn(u16) -> bool) -> Option<Listener> {
+        let info: SocketFdInfo = unsafe {
+            let mut info: SocketFdInfo = mem::zeroed();
+            let n = libc::proc_pidfdinfo(
+                pid,
+                fd,
+                PROC_PIDFDSOCKETINFO,
+                &mut info as *mut SocketFdInfo as *mut libc::c_void,
+                mem::size_of::<SocketFdInfo>() as libc::c_int,
+            );
+            if n <= 0 {
+                return None;
+            }
+            info
+        };
+
+        let psi = &info.psi;
+        let (ini, protocol) = match psi.soi_kind {
+            SOCKINFO_TCP => {
+                let tcp = unsafe { psi.soi_proto.pri_tcp };
+                if tcp.tcpsi_state != TSI_S_LISTEN {
+                    return None;
+                }
+                (tcp.tcpsi_ini, Protocol::Tcp)
+            }
+            SOCKINFO_IN if psi.soi_protocol == libc::IPPROTO_UDP => {
+                let ini = unsafe { psi.soi_proto.pri_in };
+                // connect 된 UDP 소켓(원격 포트 있음)은 서버 바인딩이 아님
+                if ini.insi_fport != 0 {
+                    return None;
+                }
+                (ini, Protocol::Udp)
+            }
+            _ => return None,
+        };
+
+        // insi_lport 하위 16bit 에 network byte order 로 저장됨
+        let port = u16::from_be(ini.insi_lport as u16);
+        if port == 0 || !wanted(port) {
+            return None;
+        }
+        let (address, family) = if ini.insi_vflag & INI_IPV4 != 0 {
+            let a = &ini.insi_laddr;
+            (Ipv4Addr::new(a[12], a[13], a[14], a[15]).to_string(), Family::Ipv4)
+        } else if ini.insi_vflag & INI_IPV6 != 0 {
+            (Ipv6Addr::from(ini.insi_laddr).to_string(), Family::Ipv6)
+        } else {
+            return None;
+        };
+
+        Some(Listener { pid: pid as u32, port, address, family, protocol })
+    }
+}
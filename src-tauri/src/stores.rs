@@ -0,0 +1,260 @@
+//! portal.json / workspace-roots.json 타입 모델
+//!
+//! 프론트엔드(PortalManager.tsx, App.tsx)의 인터페이스와 같은 모양. 알 수 없는 필드는
+//! `extra` 에 그대로 보관해 다시 저장하므로, 새 버전 프론트엔드가 추가한 필드도 잃지 않는다.
+//!
+//! 로드는 관대하게: 깨진 항목만 건너뛰고 나머지는 살린다. 건너뛴 항목은 원본 그대로
+//! 보관했다가 저장할 때 다시 쓰므로, 프론트엔드가 모르는 항목 때문에 데이터가 줄지 않는다.
+//! 저장은 엄격하게: 빈 id / 중복 id 는 거부한다 (workspace root 는 빈 경로도).
+
+use std::collections::HashSet;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+const COLORS: &[&str] = &["blue", "green", "purple", "amber", "rose", "cyan", "orange", "teal", "indigo", "pink"];
+
+fn default_color() -> String {
+    "blue".to_string()
+}
+
+/// `null` 도 기본값으로 (Supabase 에서 pull 한 행은 빈 컬럼이 null)
+fn null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PortalItemType {
+    Web,
+    Folder,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortalItem {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: PortalItemType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, deserialize_with = "null_default")]
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "null_default")]
+    pub pinned: bool,
+    #[serde(default, deserialize_with = "null_default")]
+    pub visit_count: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_visited: Option<String>,
+    #[serde(default, deserialize_with = "null_default")]
+    pub created_at: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortalCategory {
+    pub id: String,
+    pub name: String,
+    /// COLORS 중 하나 — 모르는 값은 blue
+    #[serde(default = "default_color")]
+    pub color: String,
+    #[serde(default, deserialize_with = "null_default")]
+    pub order: i64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortalData {
+    #[serde(default)]
+    pub items: Vec<PortalItem>,
+    #[serde(default)]
+    pub categories: Vec<PortalCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supabase_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supabase_anon_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// 설정되어 있으면 Pull 이 이 기기의 데이터를 보여줌
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewing_device_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_synced: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+    /// 파싱하지 못한 items / categories 원본 — 프론트엔드에는 보내지 않고 저장할 때 다시 씀
+    #[serde(skip)]
+    pub unparsed_items: Vec<Value>,
+    #[serde(skip)]
+    pub unparsed_categories: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceRoot {
+    pub id: String,
+    pub name: String,
+    /// 절대경로 (Tauri) 또는 디렉토리 이름 (Web)
+    pub path: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// 예전 프론트엔드가 JSON 문자열을 그대로 저장한 파일(`"{\"items\":...}"`) 도 풀어서 읽음
+fn unwrap_string(value: Value) -> Value {
+    match value {
+        Value::String(s) => serde_json::from_str(&s).unwrap_or(Value::Null),
+        other => other,
+    }
+}
+
+/// 배열 항목을 하나씩 파싱 — 깨진 항목은 원본 그대로 따로 돌려줌
+fn parse_each<T: DeserializeOwned>(value: Option<Value>, label: &str) -> (Vec<T>, Vec<Value>) {
+    let Some(Value::Array(entries)) = value else { return (Vec::new(), Vec::new()) };
+    let mut parsed = Vec::new();
+    let mut unparsed = Vec::new();
+    for (i, entry) in entries.into_iter().enumerate() {
+        match serde_json::from_value(entry.clone()) {
+            Ok(value) => parsed.push(value),
+            Err(e) => {
+                println!("[Stores] Skipping invalid {} #{}: {}", label, i, e);
+                unparsed.push(entry);
+            }
+        }
+    }
+    (parsed, unparsed)
+}
+
+/// 저장할 배열 뒤에 파싱하지 못한 원본 항목을 붙임 — 같은 id 가 이미 있으면 (다시 만든 항목) 버림
+fn append_unparsed(entries: &mut Vec<Value>, unparsed: &[Value]) {
+    let ids: HashSet<String> = entries
+        .iter()
+        .filter_map(|e| e.get("id")?.as_str().map(str::to_string))
+        .collect();
+    entries.extend(
+        unparsed
+            .iter()
+            .filter(|e| e.get("id").and_then(Value::as_str).is_none_or(|id| !ids.contains(id)))
+            .cloned(),
+    );
+}
+
+/// 빈 id / 중복 id 검사
+fn check_ids<'a>(ids: impl Iterator<Item = &'a str>, label: &str) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if id.trim().is_empty() {
+            return Err(format!("{} has an empty id", label));
+        }
+        if !seen.insert(id) {
+            return Err(format!("Duplicate {} id: {}", label, id));
+        }
+    }
+    Ok(())
+}
+
+impl PortalData {
+    pub fn from_value(value: Value) -> Self {
+        let Value::Object(mut obj) = unwrap_string(value) else {
+            println!("[Stores] portal.json is not an object — starting empty");
+            return PortalData::default();
+        };
+        let (items, unparsed_items) = parse_each(obj.remove("items"), "portal item");
+        let (categories, unparsed_categories) = parse_each(obj.remove("categories"), "portal category");
+        let mut data: PortalData = serde_json::from_value(Value::Object(obj)).unwrap_or_else(|e| {
+            println!("[Stores] Invalid portal settings, using defaults: {}", e);
+            PortalData::default()
+        });
+        data.items = items;
+        data.categories = categories;
+        data.unparsed_items = unparsed_items;
+        data.unparsed_categories = unparsed_categories;
+        data.normalize();
+        data
+    }
+
+    /// 디스크에 있던 파싱하지 못한 항목을 이어받음 (프론트엔드가 보낸 데이터에는 없으므로)
+    pub fn keep_unparsed(&mut self, on_disk: PortalData) {
+        self.unparsed_items = on_disk.unparsed_items;
+        self.unparsed_categories = on_disk.unparsed_categories;
+    }
+
+    /// 저장용 JSON — 파싱하지 못한 항목을 원본 그대로 다시 붙임
+    pub fn to_value(&self) -> Result<Value, String> {
+        let mut value = serde_json::to_value(self).map_err(|e| e.to_string())?;
+        if let Some(Value::Array(items)) = value.get_mut("items") {
+            append_unparsed(items, &self.unparsed_items);
+        }
+        if let Some(Value::Array(categories)) = value.get_mut("categories") {
+            append_unparsed(categories, &self.unparsed_categories);
+        }
+        Ok(value)
+    }
+
+    fn normalize(&mut self) {
+        for category in &mut self.categories {
+            if !COLORS.contains(&category.color.as_str()) {
+                category.color = default_color();
+            }
+        }
+    }
+
+    /// 저장 전 검증 + 기본값 보정
+    pub fn validate(&mut self) -> Result<(), String> {
+        check_ids(self.items.iter().map(|i| i.id.as_str()), "portal item")?;
+        check_ids(self.categories.iter().map(|c| c.id.as_str()), "portal category")?;
+        for item in &self.items {
+            let (field, target) = match item.kind {
+                PortalItemType::Web => ("url", &item.url),
+                PortalItemType::Folder => ("path", &item.path),
+            };
+            // 열 수 없는 항목일 뿐이므로 저장은 막지 않음
+            if target.as_deref().is_none_or(|t| t.trim().is_empty()) {
+                println!("[Stores] Portal item {} ({}) has no {}", item.name, item.id, field);
+            }
+        }
+        self.normalize();
+        Ok(())
+    }
+}
+
+pub fn workspace_roots_from_value(value: Value) -> Vec<WorkspaceRoot> {
+    parse_each(Some(unwrap_string(value)), "workspace root").0
+}
+
+/// 저장용 JSON — `on_disk` (현재 파일) 에서 파싱하지 못한 항목을 원본 그대로 다시 붙임
+pub fn workspace_roots_to_value(roots: &[WorkspaceRoot], on_disk: Option<Value>) -> Result<Value, String> {
+    let mut entries = roots
+        .iter()
+        .map(|r| serde_json::to_value(r).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(existing) = on_disk {
+        let (_, unparsed) = parse_each::<WorkspaceRoot>(Some(unwrap_string(existing)), "workspace root");
+        append_unparsed(&mut entries, &unparsed);
+    }
+    Ok(Value::Array(entries))
+}
+
+/// 저장 전 검증 — 빈 id / 중복 id / 빈 경로 거부
+pub fn validate_workspace_roots(roots: &[WorkspaceRoot]) -> Result<(), String> {
+    check_ids(roots.iter().map(|r| r.id.as_str()), "workspace root")?;
+    match roots.iter().find(|r| r.path.trim().is_empty()) {
+        Some(root) => Err(format!("Workspace root {} has an empty path", root.name)),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,455 @@
+//! 서버 프로세스 실행 + 감시 (Supervisor)
+//!
+//! `execute_command` / `force_restart_command` 가 띄운 `bash` 프로세스의 `Child` 핸들을
+//! 감시 스레드가 소유하고, 종료를 감지하면 exit code / signal 을 기록한 뒤
+//! PortInfo 의 `restartPolicy` 에 따라 재시작한다. 상태가 바뀔 때마다
+//! `process-state` 이벤트를 프론트엔드로 보낸다.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+use crate::procinfo::now_secs;
+use crate::capture::{self, Capture};
+use crate::{runs, tracked};
+
+/// 이 시간 이상 정상 동작한 뒤 종료되면 재시작 횟수를 초기화 (연속 크래시만 카운트)
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartMode {
+    #[default]
+    Never,
+    OnFailure,
+    Always,
+}
+
+fn default_backoff_ms() -> u64 { 1_000 }
+fn default_max_backoff_ms() -> u64 { 30_000 }
+fn default_max_retries() -> u32 { 5 }
+
+/// PortInfo 별 자동 재시작 정책
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartPolicy {
+    #[serde(default)]
+    pub mode: RestartMode,
+    /// 첫 재시작 대기 시간 — 재시작할 때마다 2배씩 증가
+    #[serde(default = "default_backoff_ms")]
+    pub backoff_ms: u64,
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// 연속 재시작 최대 횟수 — 초과하면 `failed` 상태로 포기
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy {
+            mode: RestartMode::Never,
+            backoff_ms: default_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+impl RestartPolicy {
+    fn should_restart(&self, status: &ExitStatus) -> bool {
+        match self.mode {
+            RestartMode::Never => false,
+            RestartMode::OnFailure => !status.success(),
+            RestartMode::Always => true,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let ms = self.backoff_ms.saturating_mul(1u64 << attempt.min(16)).min(self.max_backoff_ms);
+        Duration::from_millis(ms)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessState {
+    Running,
+    /// 스스로 종료됨 (재시작 정책 없음 / 정상 종료)
+    Exited,
+    /// backoff 대기 후 재시작 예정
+    Restarting,
+    /// stop_command 등으로 사용자가 중지함
+    Stopped,
+    /// 최대 재시작 횟수 초과 또는 재시작 spawn 실패
+    Failed,
+}
+
+/// `process-state` 이벤트 payload 겸 `get_process_states` 응답
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessSnapshot {
+    pub port_id: String,
+    pub pid: u32,
+    pub state: ProcessState,
+    pub exit_code: Option<i32>,
+    /// 시그널로 종료된 경우 시그널 번호 (Unix 전용)
+    pub signal: Option<i32>,
+    pub restarts: u32,
+    pub started_at: u64,
+    pub exited_at: Option<u64>,
+}
+
+/// 프로세스 하나를 띄우는 데 필요한 정보 — 재시작 시 그대로 재사용
+#[derive(Debug, Clone)]
+pub struct LaunchSpec {
+    pub port_id: String,
+    /// 절대경로면 스크립트 파일, 아니면 `bash -c` 로 실행할 raw 커맨드
+    pub command_path: String,
+    /// raw 커맨드의 작업 디렉토리
+    pub folder_path: Option<String>,
+    pub log_file: PathBuf,
+    /// `PORT` 환경변수로 넘길 포트 (충돌 시 빈 포트로 실행하는 경우)
+    pub port: Option<u16>,
+}
+
+impl LaunchSpec {
+    fn is_file_path(&self) -> bool {
+        self.command_path.starts_with('/') || self.command_path.starts_with('~')
+    }
+}
+
+struct Slot {
+    /// 같은 port_id 로 다시 launch 되면 증가 — 이전 감시 스레드는 자신이 낡았음을 알고 종료
+    generation: u64,
+    stopping: bool,
+    snapshot: ProcessSnapshot,
+}
+
+#[derive(Default)]
+pub struct Supervisor {
+    slots: Arc<Mutex<HashMap<String, Slot>>>,
+}
+
+impl Supervisor {
+    /// 프로세스를 띄우고 감시 스레드를 붙인다. 반환값은 PID
+    pub fn launch(&self, app_handle: &tauri::AppHandle, spec: LaunchSpec, policy: RestartPolicy, tag: &str) -> Result<u32, String> {
+        let spawned = spawn(&spec, tag)?;
+        let pid = spawned.child.id();
+
+        let generation = {
+            let mut slots = self.slots.lock().unwrap();
+            let generation = slots.get(&spec.port_id).map(|s| s.generation + 1).unwrap_or(0);
+            let snapshot = ProcessSnapshot {
+                port_id: spec.port_id.clone(),
+                pid,
+                state: ProcessState::Running,
+                exit_code: None,
+                signal: None,
+                restarts: 0,
+                started_at: now_secs(),
+                exited_at: None,
+            };
+            slots.insert(spec.port_id.clone(), Slot { generation, stopping: false, snapshot: snapshot.clone() });
+            emit(app_handle, &snapshot);
+            generation
+        };
+        track_pid(app_handle, &spec.port_id, pid);
+
+        let slots = Arc::clone(&self.slots);
+        let app = app_handle.clone();
+        std::thread::spawn(move || monitor(app, slots, spec, policy, generation, spawned));
+
+        Ok(pid)
+    }
+
+    /// 사용자가 중지를 요청함 — 감시 스레드가 종료를 재시작 대신 `stopped` 로 기록하도록 표시
+    pub fn mark_stopping(&self, port_id: &str) {
+        if let Some(slot) = self.slots.lock().unwrap().get_mut(port_id) {
+            slot.stopping = true;
+        }
+    }
+
+    pub fn snapshot(&self, port_id: &str) -> Option<ProcessSnapshot> {
+        self.slots.lock().unwrap().get(port_id).map(|s| s.snapshot.clone())
+    }
+
+    pub fn snapshots(&self) -> Vec<ProcessSnapshot> {
+        self.slots.lock().unwrap().values().map(|s| s.snapshot.clone()).collect()
+    }
+}
+
+/// 감시 루프: wait → 상태 기록 → (정책에 따라) backoff 후 재시작 → 반복
+fn monitor(
+    app: tauri::AppHandle,
+    slots: Arc<Mutex<HashMap<String, Slot>>>,
+    spec: LaunchSpec,
+    policy: RestartPolicy,
+    generation: u64,
+    mut spawned: Spawned,
+) {
+    let mut restarts: u32 = 0;
+    loop {
+        let started = Instant::now();
+        let Spawned { mut child, run, output } = spawned;
+        let pid = child.id();
+        let status = match child.wait() {
+            Ok(status) => status,
+            Err(e) => {
+                println!("[Supervisor] wait() failed for {} (PID {}): {}", spec.port_id, pid, e);
+                return;
+            }
+        };
+        let ran_for = started.elapsed();
+        if ran_for >= STABLE_RUN {
+            restarts = 0;
+        }
+        let (exit_code, signal) = exit_details(&status);
+        // 남은 출력을 모두 기록한 뒤 종료 푸터
+        output.drain(capture::DRAIN_TIMEOUT);
+        runs::finish(&spec.log_file, run, exit_code, signal, ran_for);
+        println!("[Supervisor] {} (PID {}) exited: code={:?} signal={:?}", spec.port_id, pid, exit_code, signal);
+
+        // 종료 상태 결정 (lock 범위 안에서 stopping / generation 확인)
+        let next = {
+            let mut guard = slots.lock().unwrap();
+            let Some(slot) = guard.get_mut(&spec.port_id) else { return };
+            if slot.generation != generation {
+                return; // 새 launch 가 이 슬롯을 가져감
+            }
+            let state = if slot.stopping {
+                ProcessState::Stopped
+            } else if !policy.should_restart(&status) {
+                ProcessState::Exited
+            } else if restarts >= policy.max_retries {
+                ProcessState::Failed
+            } else {
+                ProcessState::Restarting
+            };
+            slot.snapshot.state = state;
+            slot.snapshot.exit_code = exit_code;
+            slot.snapshot.signal = signal;
+            slot.snapshot.exited_at = Some(now_secs());
+            emit(&app, &slot.snapshot);
+            state
+        };
+
+        if next != ProcessState::Restarting {
+            untrack_pid(&app, &spec.port_id, pid);
+            return;
+        }
+
+        let delay = policy.backoff(restarts);
+        println!("[Supervisor] Restarting {} in {:?} (attempt {}/{})", spec.port_id, delay, restarts + 1, policy.max_retries);
+        std::thread::sleep(delay);
+
+        // 대기 중에 stop / 재실행 요청이 들어왔는지 다시 확인
+        {
+            let mut guard = slots.lock().unwrap();
+            let Some(slot) = guard.get_mut(&spec.port_id) else { return };
+            if slot.generation != generation {
+                return;
+            }
+            if slot.stopping {
+                slot.snapshot.state = ProcessState::Stopped;
+                emit(&app, &slot.snapshot);
+                untrack_pid(&app, &spec.port_id, pid);
+                return;
+            }
+        }
+
+        restarts += 1;
+        spawned = match spawn(&spec, "[Supervisor]") {
+            Ok(spawned) => spawned,
+            Err(e) => {
+                println!("[Supervisor] Restart of {} failed: {}", spec.port_id, e);
+                if let Some(slot) = slots.lock().unwrap().get_mut(&spec.port_id) {
+                    slot.snapshot.state = ProcessState::Failed;
+                    emit(&app, &slot.snapshot);
+                }
+                untrack_pid(&app, &spec.port_id, pid);
+                return;
+            }
+        };
+        let new_pid = spawned.child.id();
+        if let Some(slot) = slots.lock().unwrap().get_mut(&spec.port_id) {
+            slot.snapshot = ProcessSnapshot {
+                port_id: spec.port_id.clone(),
+                pid: new_pid,
+                state: ProcessState::Running,
+                exit_code: None,
+                signal: None,
+                restarts,
+                started_at: now_secs(),
+                exited_at: None,
+            };
+            emit(&app, &slot.snapshot);
+        }
+        track_pid(&app, &spec.port_id, new_pid);
+    }
+}
+
+fn exit_details(status: &ExitStatus) -> (Option<i32>, Option<i32>) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        (status.code(), status.signal())
+    }
+    #[cfg(not(unix))]
+    {
+        (status.code(), None)
+    }
+}
+
+fn emit(app: &tauri::AppHandle, snapshot: &ProcessSnapshot) {
+    let _ = app.emit("process-state", snapshot.clone());
+}
+
+fn track_pid(app: &tauri::AppHandle, port_id: &str, pid: u32) {
+    tracked::insert(app, port_id, pid);
+}
+
+/// 추적 맵의 PID 가 아직 이 프로세스일 때만 제거 (그 사이 재실행된 경우 보존)
+fn untrack_pid(app: &tauri::AppHandle, port_id: &str, pid: u32) {
+    tracked::remove_if(app, port_id, pid);
+}
+
+/// 실행된 프로세스 + run 번호 + 출력 reader
+pub struct Spawned {
+    pub child: Child,
+    pub run: u64,
+    pub output: Capture,
+}
+
+/// `bash` 로 서버를 실행 — stdout/stderr 는 파이프로 받아 로그 파일에 (`capture.rs`), 새 세션(setsid)으로 분리
+///
+/// 실행마다 로그에 run 헤더를 남긴다 (종료 푸터는 `monitor` 가 기록).
+pub fn spawn(spec: &LaunchSpec, tag: &str) -> Result<Spawned, String> {
+    let is_file_path = spec.is_file_path();
+    let command_path = &spec.command_path;
+
+    // 로그 파일을 미리 만들어 쓰기 가능한지 확인 (append 모드)
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&spec.log_file)
+        .map_err(|e| format!("Failed to open log file: {}", e))?;
+
+    // .command 파일에 실행 권한 부여 (파일 경로인 경우만)
+    if is_file_path {
+        let chmod_result = Command::new("chmod")
+            .arg("+x")
+            .arg(command_path)
+            .output();
+
+        match chmod_result {
+            Ok(out) => {
+                if out.status.success() {
+                    println!("{} Successfully set execute permission", tag);
+                } else {
+                    println!("{} Warning: chmod failed: {}", tag, String::from_utf8_lossy(&out.stderr));
+                }
+            }
+            Err(e) => {
+                println!("{} Warning: chmod error: {}", tag, e);
+            }
+        }
+    }
+
+    // 환경변수 설정 (GUI 앱에서 터미널 환경변수 상속)
+    let home = std::env::var("HOME").unwrap_or_default();
+    let new_path = shell_path();
+
+    // 프로세스 실행 시 stdout, stderr를 파이프로 받아 로그 파일에 기록
+    // setsid를 사용하여 새로운 세션으로 실행 (백그라운드 프로세스)
+    if is_file_path {
+        println!("{} Executing: bash {}", tag, command_path);
+    } else {
+        println!("{} Executing: bash -c {}", tag, command_path);
+    }
+    println!("{} PATH: {}", tag, new_path);
+
+    let mut cmd = Command::new("bash");
+    if is_file_path {
+        cmd.arg(command_path);
+    } else {
+        cmd.arg("-c").arg(command_path);
+    }
+    // raw 커맨드(terminalCommand)는 folderPath를 cwd로 설정
+    let mut cwd = std::env::current_dir().ok().map(|d| d.to_string_lossy().to_string());
+    if !is_file_path {
+        if let Some(ref fp) = spec.folder_path {
+            if !fp.is_empty() {
+                cmd.current_dir(fp);
+                cwd = Some(fp.clone());
+            }
+        }
+    }
+    cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .env("PATH", &new_path)
+        .env("HOME", &home);
+    if let Some(port) = spec.port {
+        println!("{} PORT: {}", tag, port);
+        cmd.env("PORT", port.to_string());
+    }
+
+    let start = runs::begin(&spec.log_file, command_path, cwd.as_deref(), &new_path);
+
+    // 새로운 프로세스 그룹으로 실행 (백그라운드 데몬화) — Unix 전용
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        unsafe {
+            cmd.pre_exec(|| {
+                // 새로운 세션 리더가 되어 부모와 독립적으로 실행
+                libc::setsid();
+                Ok(())
+            });
+        }
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            runs::finish(&spec.log_file, start.run, None, None, Duration::ZERO);
+            return Err(format!("Failed to spawn process: {}", e));
+        }
+    };
+    // 헤더를 먼저 쓰고 reader 시작 — 그 사이 출력은 파이프 버퍼에 남아 헤더 뒤에 기록됨
+    runs::started(&spec.log_file, &start, child.id());
+    let output = Capture::start(&mut child, &spec.log_file);
+    Ok(Spawned { child, run: start.run, output })
+}
+
+/// GUI 앱은 로그인 셸의 PATH 를 상속하지 않으므로 일반적인 도구 경로를 앞에 추가
+pub fn shell_path() -> String {
+    let home = std::env::var("HOME").unwrap_or_default();
+
+    // PATH 환경변수에 일반적인 경로들 추가
+    let path_additions = vec![
+        format!("{}/.cargo/bin", home),
+        format!("{}/.bun/bin", home),
+        format!("{}/bin", home),
+        "/usr/local/bin".to_string(),
+        "/usr/bin".to_string(),
+        "/bin".to_string(),
+        "/usr/sbin".to_string(),
+        "/sbin".to_string(),
+        "/opt/homebrew/bin".to_string(),
+        "/usr/local/go/bin".to_string(),
+    ];
+
+    let existing_path = std::env::var("PATH").unwrap_or_default();
+    if existing_path.is_empty() {
+        path_additions.join(":")
+    } else {
+        format!("{}:{}", path_additions.join(":"), existing_path)
+    }
+}
@@ -0,0 +1,134 @@
+//! 추적 중인 서버 PID 영속화 (`processes.json`)
+//!
+//! `AppState.processes` 는 메모리에만 있으므로 앱 재시작 / 업데이트 후에는
+//! 앱이 띄운 서버를 더 이상 세션 단위로 종료할 수 없다. 맵이 바뀔 때마다
+//! PID 와 시작 토큰을 app data 디렉토리에 기록하고, 시작 시 다시 채택한다.
+//!
+//! 시작 토큰이 다르면 같은 번호의 다른 프로세스(PID 재사용)이므로 버린다.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+use crate::{persist, procinfo, AppState};
+
+const FILE_NAME: &str = "processes.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackedProcess {
+    port_id: String,
+    pid: u32,
+    /// 프로세스 시작 시각 (Unix epoch 초)
+    #[serde(default)]
+    started_at: Option<u64>,
+    /// `procinfo::start_token` — 채택 시 PID 재사용 판별
+    #[serde(default)]
+    start_token: Option<String>,
+}
+
+impl TrackedProcess {
+    fn new(port_id: &str, pid: u32) -> Self {
+        TrackedProcess {
+            port_id: port_id.to_string(),
+            pid,
+            started_at: procinfo::process_start_time(pid),
+            start_token: procinfo::start_token(pid),
+        }
+    }
+
+    /// 기록된 프로세스가 아직 같은 프로세스로 살아있는지
+    fn is_same_process(&self) -> bool {
+        // 토큰을 얻을 수 없는 플랫폼에서는 PID 재사용을 구분할 수 없으므로 채택하지 않음
+        let Some(token) = self.start_token.as_deref() else { return false };
+        procinfo::is_alive(self.pid) && procinfo::start_token(self.pid).as_deref() == Some(token)
+    }
+}
+
+fn read(app: &tauri::AppHandle) -> Vec<TrackedProcess> {
+    let Ok(app_data_dir) = app.path().app_data_dir() else { return Vec::new() };
+    persist::read_json(&app_data_dir.join(FILE_NAME))
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// 현재 추적 맵을 `processes.json` 에 기록
+///
+/// 이미 기록된 PID 는 기존 토큰을 유지하고, 새 PID 만 토큰을 새로 읽는다.
+fn write(app: &tauri::AppHandle, processes: &HashMap<String, u32>) {
+    let Ok(app_data_dir) = app.path().app_data_dir() else { return };
+    let previous = read(app);
+    let mut entries: Vec<TrackedProcess> = processes
+        .iter()
+        .map(|(port_id, &pid)| {
+            previous
+                .iter()
+                .find(|e| &e.port_id == port_id && e.pid == pid)
+                .cloned()
+                .unwrap_or_else(|| TrackedProcess::new(port_id, pid))
+        })
+        .collect();
+    entries.sort_by(|a, b| a.port_id.cmp(&b.port_id));
+
+    if let Err(e) = persist::write_json(&app_data_dir.join(FILE_NAME), &entries) {
+        println!("[Tracked] Failed to save {}: {}", FILE_NAME, e);
+    }
+}
+
+/// 추적 맵에 PID 등록 (기존 항목 교체)
+pub fn insert(app: &tauri::AppHandle, port_id: &str, pid: u32) {
+    let state = app.state::<AppState>();
+    let mut processes = state.processes.lock().unwrap();
+    processes.insert(port_id.to_string(), pid);
+    write(app, &processes);
+}
+
+/// 추적 맵에서 제거 — 제거된 PID 반환
+pub fn remove(app: &tauri::AppHandle, port_id: &str) -> Option<u32> {
+    let state = app.state::<AppState>();
+    let mut processes = state.processes.lock().unwrap();
+    let pid = processes.remove(port_id);
+    if pid.is_some() {
+        write(app, &processes);
+    }
+    pid
+}
+
+/// 추적 맵의 PID 가 아직 `pid` 일 때만 제거 (그 사이 재실행된 경우 보존)
+pub fn remove_if(app: &tauri::AppHandle, port_id: &str, pid: u32) {
+    let state = app.state::<AppState>();
+    let mut processes = state.processes.lock().unwrap();
+    if processes.get(port_id) == Some(&pid) {
+        processes.remove(port_id);
+        write(app, &processes);
+    }
+}
+
+/// 죽은 PID 정리 — Supervisor 감시 밖에 있는 채택된 프로세스용
+pub fn prune(app: &tauri::AppHandle) {
+    let state = app.state::<AppState>();
+    let mut processes = state.processes.lock().unwrap();
+    let before = processes.len();
+    processes.retain(|_, pid| procinfo::is_alive(*pid));
+    if processes.len() != before {
+        write(app, &processes);
+    }
+}
+
+/// 시작 시 `processes.json` 의 프로세스 중 아직 살아있는 것만 추적 맵으로 채택
+pub fn adopt(app: &tauri::AppHandle) {
+    let entries = read(app);
+    let state = app.state::<AppState>();
+    let mut processes = state.processes.lock().unwrap();
+    for entry in &entries {
+        if entry.is_same_process() {
+            println!("[Tracked] Re-adopted PID {} for {}", entry.pid, entry.port_id);
+            processes.insert(entry.port_id.clone(), entry.pid);
+        } else {
+            println!("[Tracked] Pruned stale PID {} for {}", entry.pid, entry.port_id);
+        }
+    }
+    // 버린 항목을 파일에서도 제거 (채택한 항목은 기존 토큰 유지)
+    write(app, &processes);
+}
@@ -0,0 +1,146 @@
+//! ports.json 저장 전 검증
+//!
+//! | code             | severity | 내용                                         |
+//! |------------------|----------|----------------------------------------------|
+//! | `empty-id`       | error    | id 가 비어 있음                              |
+//! | `duplicate-id`   | error    | 같은 id 가 여러 항목에 있음                  |
+//! | `duplicate-port` | error    | 같은 port 를 여러 항목이 사용                |
+//! | `invalid-url`    | error    | deployUrl / githubUrl 이 http(s) URL 이 아님 |
+//! | `missing-path`   | warning  | folderPath / commandPath 가 존재하지 않음    |
+//!
+//! 경로는 외장 드라이브 / 다른 기기(sourceDeviceId)에서 가져온 항목일 수 있으므로 경고로만 처리.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use serde::Serialize;
+
+use crate::PortInfo;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub port_id: String,
+    /// 문제가 된 PortInfo 필드 (JSON 키 이름)
+    pub field: &'static str,
+    pub message: String,
+    /// 같은 포트를 쓰는 다른 항목 id (duplicate-port)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_ids: Vec<String>,
+}
+
+impl ValidationIssue {
+    fn new(severity: Severity, code: &'static str, port_id: &str, field: &'static str, message: String) -> Self {
+        ValidationIssue {
+            severity,
+            code,
+            port_id: port_id.to_string(),
+            field,
+            message,
+            related_ids: Vec::new(),
+        }
+    }
+}
+
+/// strict 저장 거부 시 에러 payload (JSON 문자열)
+pub fn to_error(issues: &[ValidationIssue]) -> String {
+    serde_json::json!({ "kind": "validation", "issues": issues }).to_string()
+}
+
+pub fn has_errors(issues: &[ValidationIssue]) -> bool {
+    issues.iter().any(|i| i.severity == Severity::Error)
+}
+
+pub fn validate(ports: &[PortInfo]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    let mut by_id: HashMap<&str, Vec<&PortInfo>> = HashMap::new();
+    let mut by_port: HashMap<u16, Vec<&PortInfo>> = HashMap::new();
+    for p in ports {
+        by_id.entry(p.id.as_str()).or_default().push(p);
+        if let Some(port) = p.port {
+            by_port.entry(port).or_default().push(p);
+        }
+    }
+
+    for p in ports {
+        if p.id.trim().is_empty() {
+            issues.push(ValidationIssue::new(Severity::Error, "empty-id", &p.id, "id", format!("{} has no id", p.name)));
+        } else if by_id[p.id.as_str()].len() > 1 {
+            let names: Vec<&str> = by_id[p.id.as_str()].iter().map(|o| o.name.as_str()).collect();
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                "duplicate-id",
+                &p.id,
+                "id",
+                format!("id {} is used by {} entries: {}", p.id, names.len(), names.join(", ")),
+            ));
+        }
+
+        if let Some(port) = p.port {
+            let others: Vec<String> = by_port[&port].iter().filter(|o| !std::ptr::eq(**o, p)).map(|o| o.id.clone()).collect();
+            if !others.is_empty() {
+                let mut issue = ValidationIssue::new(
+                    Severity::Error,
+                    "duplicate-port",
+                    &p.id,
+                    "port",
+                    format!("Port {} of {} is also used by {} other entr{}", port, p.name, others.len(), if others.len() == 1 { "y" } else { "ies" }),
+                );
+                issue.related_ids = others;
+                issues.push(issue);
+            }
+        }
+
+        if let Some(folder) = non_empty(&p.folder_path) {
+            if !expand_home(folder).is_dir() {
+                issues.push(ValidationIssue::new(Severity::Warning, "missing-path", &p.id, "folderPath", format!("Folder not found: {}", folder)));
+            }
+        }
+        if let Some(command) = non_empty(&p.command_path) {
+            let path = expand_home(command);
+            // 절대경로가 아니면 raw 커맨드이므로 검사하지 않음
+            if (command.starts_with('~') || path.is_absolute()) && !path.exists() {
+                issues.push(ValidationIssue::new(Severity::Warning, "missing-path", &p.id, "commandPath", format!("Command file not found: {}", command)));
+            }
+        }
+
+        for (field, url) in [("deployUrl", &p.deploy_url), ("githubUrl", &p.github_url)] {
+            if let Some(url) = non_empty(url) {
+                if !is_http_url(url) {
+                    issues.push(ValidationIssue::new(Severity::Error, "invalid-url", &p.id, field, format!("Invalid URL: {}", url)));
+                }
+            }
+        }
+    }
+    issues
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix('~') {
+        Some(rest) => {
+            let home = std::env::var("HOME").unwrap_or_default();
+            PathBuf::from(format!("{}{}", home, rest))
+        }
+        None => PathBuf::from(path),
+    }
+}
+
+/// `http(s)://host[...]` 형식인지 (공백 없음, host 비어 있지 않음)
+fn is_http_url(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")) else { return false };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    !host.is_empty() && !url.chars().any(char::is_whitespace)
+}
@@ -0,0 +1,217 @@
+//! 백그라운드 상태 감시 → 프론트엔드 이벤트 push
+//!
+//! UI 가 `check_port_status` / `read_log_content` 를 주기적으로 폴링하는 대신,
+//! 이 스레드가 변화가 있을 때만 이벤트를 보낸다.
+//!
+//! | 이벤트            | 발생 시점                                        |
+//! |-------------------|--------------------------------------------------|
+//! | `process-started` | `AppState.processes` 에 PID 가 새로 등록됨       |
+//! | `process-exited`  | 추적 중이던 PID 가 사라지거나 죽음               |
+//! | `port-bound`      | ports.json 의 포트에 리스너가 생김               |
+//! | `port-released`   | 리스너가 모두 사라짐                             |
+//! | `log-appended`    | `logs/<id>.log` 크기가 바뀜 (줄어들면 rotation)  |
+//! | `log-error`       | 새 로그에서 에러 패턴 감지 / 재시작으로 해제     |
+
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::Serialize;
+use tauri::{Emitter, Manager};
+
+use crate::logscan::{self, Detection};
+use crate::{cached_ports, capture, logs, procinfo, sockets, tracked, AppState, PortInfo};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// 소켓 스캔은 상대적으로 비싸므로 N 틱마다 한 번
+const PORT_SCAN_EVERY: u64 = 2;
+/// 로그 rotation 조건 확인 주기 (틱)
+const LOG_ROTATE_EVERY: u64 = 30;
+/// 한 틱에 에러 감지로 읽을 새 로그 최대 크기
+const DETECT_MAX_BYTES: usize = 256 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessEvent {
+    port_id: String,
+    pid: u32,
+    exit_code: Option<i32>,
+    signal: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PortEvent {
+    port: u16,
+    /// 이 포트를 사용하는 PortInfo id 들
+    port_ids: Vec<String>,
+    pids: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEvent {
+    port_id: String,
+    size: u64,
+    previous_size: u64,
+    /// 파일이 줄어듦 — rotation / truncate 되었으므로 offset 0 부터 다시 읽어야 함
+    truncated: bool,
+}
+
+#[derive(Default)]
+struct Observed {
+    processes: HashMap<String, u32>,
+    bound_ports: HashMap<u16, Vec<u32>>,
+    log_sizes: HashMap<String, u64>,
+}
+
+pub fn start(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut observed = Observed::default();
+        let mut tick: u64 = 0;
+        loop {
+            let ports = cached_ports(&app);
+            check_processes(&app, &mut observed);
+            if tick.is_multiple_of(PORT_SCAN_EVERY) {
+                let mut by_port: HashMap<u16, Vec<String>> = HashMap::new();
+                for p in &ports {
+                    if let Some(port) = p.port {
+                        by_port.entry(port).or_default().push(p.id.clone());
+                    }
+                }
+                check_ports(&app, &mut observed, &by_port);
+            }
+            if tick.is_multiple_of(LOG_ROTATE_EVERY) {
+                rotate_logs(&app, &ports);
+            }
+            check_logs(&app, &mut observed, &ports, tick == 0);
+            tick += 1;
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
+
+fn check_processes(app: &tauri::AppHandle, observed: &mut Observed) {
+    let state = app.state::<AppState>();
+    let current: HashMap<String, u32> = state.processes.lock().unwrap().clone();
+
+    for (port_id, &old_pid) in &observed.processes {
+        let still_tracked = current.get(port_id) == Some(&old_pid);
+        if !still_tracked || !procinfo::is_alive(old_pid) {
+            // Supervisor 가 알고 있는 종료 정보가 있으면 함께 전달
+            let snapshot = state.supervisor.snapshot(port_id).filter(|s| s.pid == old_pid);
+            let _ = app.emit("process-exited", ProcessEvent {
+                port_id: port_id.clone(),
+                pid: old_pid,
+                exit_code: snapshot.as_ref().and_then(|s| s.exit_code),
+                signal: snapshot.as_ref().and_then(|s| s.signal),
+            });
+        }
+    }
+    for (port_id, &pid) in &current {
+        let is_new = observed.processes.get(port_id) != Some(&pid);
+        if is_new && procinfo::is_alive(pid) {
+            let _ = app.emit("process-started", ProcessEvent {
+                port_id: port_id.clone(),
+                pid,
+                exit_code: None,
+                signal: None,
+            });
+        }
+    }
+
+    // 죽은 PID 는 다음 틱에 중복 이벤트가 나가지 않도록 관측 목록에서 제외
+    observed.processes = current
+        .into_iter()
+        .filter(|(_, pid)| procinfo::is_alive(*pid))
+        .collect();
+
+    // Supervisor 가 감시하지 않는 프로세스(이전 실행에서 채택)는 여기서 추적 해제
+    tracked::prune(app);
+}
+
+fn check_ports(app: &tauri::AppHandle, observed: &mut Observed, by_port: &HashMap<u16, Vec<String>>) {
+    let ports: Vec<u16> = by_port.keys().copied().collect();
+    let bound: HashMap<u16, Vec<u32>> = sockets::listeners_by_port(&ports)
+        .into_iter()
+        .filter(|(_, listeners)| !listeners.is_empty())
+        .map(|(port, listeners)| (port, sockets::unique_pids(&listeners)))
+        .collect();
+
+    for (&port, pids) in &bound {
+        if observed.bound_ports.get(&port) != Some(pids) {
+            let _ = app.emit("port-bound", PortEvent {
+                port,
+                port_ids: by_port.get(&port).cloned().unwrap_or_default(),
+                pids: pids.clone(),
+            });
+        }
+    }
+    for (&port, pids) in &observed.bound_ports {
+        if !bound.contains_key(&port) {
+            let _ = app.emit("port-released", PortEvent {
+                port,
+                port_ids: by_port.get(&port).cloned().unwrap_or_default(),
+                pids: pids.clone(),
+            });
+        }
+    }
+    observed.bound_ports = bound;
+}
+
+/// 크기 / 경과 시간 기준 rotation — 이어지는 check_logs 가 `truncated` 이벤트를 보냄
+fn rotate_logs(app: &tauri::AppHandle, ports: &[PortInfo]) {
+    let Ok(app_data_dir) = app.path().app_data_dir() else { return };
+    let logs_dir = app_data_dir.join("logs");
+    for p in ports {
+        let retention = p.log_retention.clone().unwrap_or_default();
+        let log_file = logs_dir.join(format!("{}.log", p.id));
+        for path in [capture::stamped_path(&log_file), log_file] {
+            match logs::rotate_if_needed(&path, &retention) {
+                Ok(true) => println!("[Watcher] Rotated {:?}", path),
+                Ok(false) => {}
+                Err(e) => println!("[Watcher] Log rotation failed for {:?}: {}", path, e),
+            }
+        }
+    }
+}
+
+fn check_logs(app: &tauri::AppHandle, observed: &mut Observed, ports: &[PortInfo], silent: bool) {
+    let Ok(app_data_dir) = app.path().app_data_dir() else { return };
+    let logs_dir = app_data_dir.join("logs");
+
+    let mut sizes = HashMap::new();
+    for p in ports {
+        let log_file = logs_dir.join(format!("{}.log", p.id));
+        let Ok(meta) = std::fs::metadata(&log_file) else { continue };
+        let size = meta.len();
+        let previous_size = observed.log_sizes.get(&p.id).copied().unwrap_or(0);
+        if !silent && size != previous_size {
+            let _ = app.emit("log-appended", LogEvent {
+                port_id: p.id.clone(),
+                size,
+                previous_size,
+                truncated: size < previous_size,
+            });
+            detect_errors(app, p, &log_file, previous_size);
+        }
+        sizes.insert(p.id.clone(), size);
+    }
+    observed.log_sizes = sizes;
+    let ids: Vec<&str> = ports.iter().map(|p| p.id.as_str()).collect();
+    app.state::<AppState>().log_errors.retain(&ids);
+}
+
+/// 새로 추가된 로그에 에러 감지기 적용 → 배지 설정 / 해제
+fn detect_errors(app: &tauri::AppHandle, p: &PortInfo, log_file: &std::path::Path, previous_size: u64) {
+    let Ok(chunk) = logs::read_chunk(log_file, previous_size, DETECT_MAX_BYTES, None) else { return };
+    let error = match logscan::detect(&chunk.content) {
+        Some(Detection::Error(error)) => Some(error),
+        Some(Detection::Restarted) => None,
+        None => return,
+    };
+    if app.state::<AppState>().log_errors.set(app, &p.id, error.clone()) {
+        match error {
+            Some(error) => println!("[Watcher] {} log error ({:?}): {}", p.id, error.kind, error.line),
+            None => println!("[Watcher] {} restarted — cleared log error", p.id),
+        }
+    }
+}
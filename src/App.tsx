@@ -30,15 +30,18 @@ const API = {
     }
   },
 
-  async savePorts(ports: PortInfo[]): Promise<void> {
+  // 저장 후 검증 경고 / 에러 반환 (웹 API 는 검증하지 않음)
+  async savePorts(ports: PortInfo[]): Promise<ValidationIssue[]> {
     if (isTauri()) {
-      return invoke('save_ports', { ports });
+      const saved = await invoke<{ ports: PortInfo[]; issues: ValidationIssue[] }>('save_ports', { ports });
+      return saved.issues;
     } else {
       await fetch('/api/ports', {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
         body: JSON.stringify(ports),
       });
+      return [];
     }
   },
 
@@ -107,6 +110,18 @@ const API = {
     }
   },
 
+  async previewImportPorts(filePath: string, strategy: ImportStrategy, workspaceRoot?: string): Promise<ImportPreview> {
+    return invoke<ImportPreview>('preview_import_ports', { filePath, strategy, workspaceRoot });
+  },
+
+  async applyImportPorts(filePath: string, strategy: ImportStrategy, workspaceRoot?: string): Promise<ImportPreview> {
+    return invoke<ImportPreview>('apply_import_ports', { filePath, strategy, workspaceRoot });
+  },
+
+  async discoverProjects(folderPath: string): Promise<DiscoveredProject[]> {
+    return invoke<DiscoveredProject[]>('discover_projects', { folderPath });
+  },
+
   async importPorts(filePath: string): Promise<PortInfo[]> {
     if (isTauri()) {
       return invoke<PortInfo[]>('import_ports_from_file', { filePath });
@@ -309,9 +324,10 @@ const API = {
     }
   },
 
-  async readLogContent(portId: string, offset: number = 0): Promise<{ content: string; size: number; exists: boolean; offset: number }> {
+  // nextOffset / fingerprint: 다음 요청에 그대로 전달, truncated: 파일이 줄어들었거나 바뀜 (rotation) — 화면을 content 로 교체
+  async readLogContent(portId: string, offset: number = 0, fingerprint?: string): Promise<{ content: string; size: number; exists: boolean; offset: number; nextOffset: number; truncated: boolean; fingerprint?: string }> {
     if (isTauri()) {
-      return invoke('read_log_content', { portId, offset });
+      return invoke('read_log_content', { portId, offset, fingerprint: fingerprint ?? null });
     } else {
       const res = await fetch(`/api/log-content/${encodeURIComponent(portId)}?offset=${offset}`);
       const data = await res.json();
@@ -320,6 +336,38 @@ const API = {
     }
   },
 
+  // 정규식 로그 검색 — portId 가 없으면 모든 프로젝트 (Tauri 전용)
+  async searchLogs(pattern: string, portId?: string, options?: { caseInsensitive?: boolean; context?: number; includeArchives?: boolean; maxMatches?: number }): Promise<{ matches: LogSearchMatch[]; limited: boolean }> {
+    if (isTauri()) {
+      return invoke('search_logs', { pattern, portId: portId ?? null, options: options ?? null });
+    } else {
+      throw new Error('로그 검색은 Tauri 앱에서만 사용 가능합니다');
+    }
+  },
+
+  // watcher 가 감지한 로그 에러 배지 (portId → 에러, 런타임 상태)
+  async getLogErrors(): Promise<Record<string, LogError>> {
+    if (isTauri()) {
+      return invoke('get_log_errors');
+    }
+    return {};
+  },
+
+  async clearLogError(portId: string): Promise<void> {
+    if (isTauri()) {
+      return invoke('clear_log_error', { portId });
+    }
+  },
+
+  // 타임스탬프 / 스트림 태그가 붙은 로그 — stream 을 주면 그 스트림만 (Tauri 전용)
+  async readStampedLog(portId: string, offset: number = 0, stream?: LogStream, fingerprint?: string): Promise<{ lines: StampedLogLine[]; size: number; exists: boolean; offset: number; nextOffset: number; truncated: boolean; fingerprint: string }> {
+    if (isTauri()) {
+      return invoke('read_stamped_log', { portId, offset, fingerprint: fingerprint ?? null, stream: stream ?? null });
+    } else {
+      throw new Error('스트림별 로그는 Tauri 앱에서만 사용 가능합니다');
+    }
+  },
+
   async openTmuxClaude(sessionName: string, folderPath?: string, worktreePath?: string): Promise<string> {
     if (isTauri()) {
       return invoke<string>('open_tmux_claude', { sessionName, folderPath: folderPath ?? null, worktreePath: worktreePath ?? null });
@@ -534,7 +582,7 @@ const CLAUDE_AI_NAME_PROMPT = `포트관리기의 프로젝트 목록에 "AI 추
 1. **백업 먼저**: 쓰기 전에 반드시 백업 생성 (코드 한 줄로 충분).
    \`cp "$HOME/Library/Application Support/com.portmanager.portmanager/ports.json" "$HOME/Library/Application Support/com.portmanager.portmanager/ports.json.bak"\`
 
-2. **읽기**: JSON을 파싱해서 배열을 메모리에 로드.
+2. **읽기**: JSON을 파싱해서 메모리에 로드. 형식은 \`{ "schemaVersion": 2, "ports": [...] }\` — 항목 배열은 \`ports\` 필드 (구버전 파일은 배열 그대로). \`schemaVersion\`은 그대로 유지.
 
 3. **각 항목에 aiName + category 설정**:
    - 이미 \`aiName\`이 있으면 건드리지 말 것 (idempotent — 재실행해도 기존 별칭 유지)
@@ -556,8 +604,8 @@ const CLAUDE_AI_NAME_PROMPT = `포트관리기의 프로젝트 목록에 "AI 추
 
 6. **필드 보존 규칙 (매우 중요)**:
    - 다음 필드는 **원본 값 그대로 유지**해야 함 — 존재한다면 삭제/수정 금지:
-     id, name, port, commandPath, terminalCommand, folderPath, deployUrl, githubUrl, worktreePath, description, isRunning
-   - \`worktreePath: null\` 같은 명시적 null 값도 그대로 유지 (삭제 금지)
+     id, name, port, commandPath, terminalCommand, folderPath, deployUrl, githubUrl, worktreePaths, description, isRunning
+   - \`worktreePaths: []\` 같은 빈 값 / 명시적 null 값도 그대로 유지 (삭제 금지)
    - \`isRunning: false\` 같은 boolean도 그대로 유지
    - 원래 없던 필드를 새로 추가하지 말 것 (aiName, category 제외)
 
@@ -595,6 +643,40 @@ interface PortInfo {
   sourceDeviceId?: string; // device_id from Supabase — used to prevent cross-device overwrite on push
 }
 
+interface ValidationIssue {
+  severity: 'warning' | 'error';
+  code: string;
+  portId: string;
+  field: string;
+  message: string;
+  relatedIds?: string[];
+}
+
+type LogErrorKind = 'port-in-use' | 'module-not-found' | 'panic' | 'unhandled-rejection' | 'python-traceback';
+
+interface LogError {
+  kind: LogErrorKind;
+  line: string;
+  detectedAt: number;
+}
+
+const LOG_ERROR_LABELS: Record<LogErrorKind, string> = {
+  'port-in-use': '포트 사용 중',
+  'module-not-found': '모듈 없음',
+  'panic': 'panic',
+  'unhandled-rejection': 'Promise 거부',
+  'python-traceback': 'Traceback',
+};
+
+interface LogSearchMatch {
+  portId: string;
+  file: string;
+  lineNumber: number;
+  text: string;
+  before: string[];
+  after: string[];
+}
+
 interface WorktreeInfo {
   path: string;
   branch?: string;
@@ -609,6 +691,33 @@ interface Toast {
   type: 'success' | 'error';
 }
 
+type ImportStrategy = 'skip-existing' | 'overwrite' | 'keep-both' | 'match-folder';
+
+interface ImportPreview {
+  strategy: ImportStrategy;
+  total: number;
+  added: { id: string; name: string }[];
+  changed: { id: string; name: string; fields: string[] }[];
+  skipped: { id: string; name: string }[];
+  renamed: { originalId: string; id: string; name: string }[];
+  conflicts: { severity: 'warning' | 'error'; code: string; portId: string; field: string; message: string; relatedIds?: string[] }[];
+  ports: PortInfo[];
+}
+
+type LogStream = 'stdout' | 'stderr' | 'run';
+
+interface StampedLogLine {
+  time: string;
+  stream: LogStream;
+  text: string;
+}
+
+interface DiscoveredProject {
+  source: 'compose' | 'procfile' | 'workspace';
+  file: string;
+  portInfo: PortInfo;
+}
+
 interface WorkspaceRoot {
   id: string;
   name: string;
@@ -918,6 +1027,8 @@ function App() {
   const hasInitiallyLoaded = useRef(false);
   const hasWorkspaceRootsLoaded = useRef(false);
   const skipNextSave = useRef(false); // 서버 리로드(focus 등)로 인한 불필요한 덮어쓰기 방지
+  const lastSaveIssues = useRef(''); // 마지막으로 알린 저장 검증 에러
+  const [logErrors, setLogErrors] = useState<Record<string, LogError>>({}); // portId → 로그 에러 배지
   const [name, setName] = useState('');
   const [port, setPort] = useState('');
   const [commandPath, setCommandPath] = useState('');
@@ -1036,7 +1147,9 @@ function App() {
   const [isLoadingPortLog, setIsLoadingPortLog] = useState(false);
   const portLogContainerRef = useRef<HTMLDivElement>(null);
   const portLogOffsetRef = useRef<number>(0);
+  const portLogFingerprintRef = useRef<string | undefined>(undefined); // rotation 감지용 (read_log_content)
   const portLogPollingRef = useRef<ReturnType<typeof setInterval> | null>(null);
+  const portLogPullRef = useRef<{ portId: string; pull: () => Promise<void> } | null>(null); // log-appended 이벤트에서 호출
   const [workspaceRoots, setWorkspaceRoots] = useState<WorkspaceRoot[]>([]);
   const [workspaceRootsOpen, setWorkspaceRootsOpen] = useState(false);
   const [visitCounts, setVisitCounts] = useState<{ portId: string; count: number }[]>([]);
@@ -1720,7 +1833,8 @@ function App() {
     });
   }, [ports]);
 
-  // 10초 간격 포트 상태 자동 폴링 (portsRef로 최신 ports 참조 — dependency loop 방지)
+  // 포트 상태 자동 폴링 (portsRef로 최신 ports 참조 — dependency loop 방지)
+  // Tauri 는 watcher 이벤트로 갱신하므로 놓친 변화만 잡는 느린 폴링, 웹은 10초
   const portsRef = useRef<PortInfo[]>([]);
   useEffect(() => { portsRef.current = ports; }, [ports]);
   useEffect(() => {
@@ -1737,10 +1851,51 @@ function App() {
         const r = results.find(r => r.id === p.id);
         return r ? { ...p, isRunning: r.isRunning } : p;
       }));
-    }, 10000);
+    }, isTauri() ? 60000 : 10000);
     return () => clearInterval(interval);
   }, []);
 
+  // watcher 의 프로세스 / 포트 이벤트로 실행 상태 갱신
+  useEffect(() => {
+    if (!isTauri()) return;
+    const setRunning = (ids: string[], running: boolean) => {
+      setPorts(prev => prev.some(p => ids.includes(p.id) && !!p.isRunning !== running)
+        ? prev.map(p => ids.includes(p.id) ? { ...p, isRunning: running } : p)
+        : prev);
+    };
+    const unlisteners: (() => void)[] = [];
+    let disposed = false;
+    import('@tauri-apps/api/event').then(({ listen }) => Promise.all([
+      listen<{ portId: string; pid: number }>('process-started', ({ payload }) => {
+        setRunning([payload.portId], true);
+      }),
+      // 프로세스가 끝나도 다른 프로세스가 포트를 잡고 있을 수 있으므로 포트로 확인
+      listen<{ portId: string; pid: number }>('process-exited', async ({ payload }) => {
+        const port = portsRef.current.find(p => p.id === payload.portId)?.port;
+        const running = port ? await API.checkPortStatus(port).catch(() => false) : false;
+        setRunning([payload.portId], running);
+      }),
+      listen<{ port: number; portIds: string[]; pids: number[] }>('port-bound', ({ payload }) => {
+        setRunning(payload.portIds, true);
+      }),
+      listen<{ port: number; portIds: string[]; pids: number[] }>('port-released', ({ payload }) => {
+        setRunning(payload.portIds, false);
+      }),
+      // 로그 뷰어가 열려 있으면 새로 쓰인 부분만 읽음
+      listen<{ portId: string; size: number; previousSize: number; truncated: boolean }>('log-appended', ({ payload }) => {
+        const viewer = portLogPullRef.current;
+        if (viewer?.portId === payload.portId) viewer.pull();
+      }),
+    ])).then(fns => {
+      if (disposed) fns.forEach(fn => fn());
+      else unlisteners.push(...fns);
+    });
+    return () => {
+      disposed = true;
+      unlisteners.forEach(fn => fn());
+    };
+  }, []);
+
   // 작업 루트 초기 로드
   useEffect(() => {
     API.loadWorkspaceRoots().then(data => {
@@ -1839,6 +1994,7 @@ function App() {
   // Port log polling cleanup
   useEffect(() => {
     return () => {
+      portLogPullRef.current = null;
       if (portLogPollingRef.current) {
         clearInterval(portLogPollingRef.current);
         portLogPollingRef.current = null;
@@ -1944,8 +2100,15 @@ function App() {
       if (import.meta.env.DEV) console.log('[App] Saving ports, count:', ports.length);
       const savePortsData = async () => {
         try {
-          await API.savePorts(ports);
+          const issues = await API.savePorts(ports);
           if (import.meta.env.DEV) console.log('[App] Ports saved successfully');
+          // 검증 에러는 내용이 바뀔 때만 알림 (자동 저장마다 반복하지 않음)
+          const errors = issues.filter(i => i.severity === 'error');
+          const key = errors.map(i => `${i.code}:${i.portId}`).join(',');
+          if (key !== lastSaveIssues.current) {
+            lastSaveIssues.current = key;
+            if (errors.length > 0) showToast(`저장됨 — 확인 필요: ${errors[0].message}${errors.length > 1 ? ` 외 ${errors.length - 1}건` : ''}`, 'error');
+          }
         } catch (error) {
           console.error('[App] Failed to save ports:', error);
         }
@@ -1954,6 +2117,24 @@ function App() {
     }
   }, [ports, isLoading]);
 
+  // 로그 에러 배지 — 현재 목록을 받아온 뒤 watcher 의 log-error 이벤트로 갱신
+  useEffect(() => {
+    if (!isTauri()) return;
+    let unlisten: (() => void) | undefined;
+    API.getLogErrors().then(setLogErrors).catch(() => {});
+    import('@tauri-apps/api/event').then(({ listen }) => {
+      listen<{ portId: string; error: LogError | null }>('log-error', ({ payload }) => {
+        setLogErrors(prev => {
+          const next = { ...prev };
+          if (payload.error) next[payload.portId] = payload.error;
+          else delete next[payload.portId];
+          return next;
+        });
+      }).then(fn => { unlisten = fn; });
+    });
+    return () => { unlisten?.(); };
+  }, []);
+
   // 창 포커스 시 데이터 다시 로드 (웹↔Tauri 동기화)
   useEffect(() => {
     const handleFocus = async () => {
@@ -2209,17 +2390,20 @@ function App() {
         const { save } = await import('@tauri-apps/plugin-dialog');
         const filePath = await save({
           defaultPath: 'ports.json',
-          filters: [{
-            name: 'JSON',
-            extensions: ['json']
-          }]
+          filters: [
+            { name: 'JSON', extensions: ['json'] },
+            { name: 'YAML', extensions: ['yaml', 'yml'] },
+            { name: 'TOML', extensions: ['toml'] },
+            { name: 'CSV', extensions: ['csv'] },
+          ]
         });
 
         if (filePath) {
-          const content = JSON.stringify(ports, null, 2);
-          const { writeTextFile } = await import('@tauri-apps/plugin-fs');
-          await writeTextFile(filePath, content);
-          showToast('포트 정보를 성공적으로 내보냈습니다.', 'success');
+          // 형식은 확장자로 결정. 공유용이면 기기 전용 필드 제거 + 첫 작업 루트 기준 상대경로
+          const workspaceRoot = workspaceRoots.find(r => r.path.startsWith('/'))?.path;
+          const portable = confirm(`다른 기기와 공유할 수 있게 경로를 ${workspaceRoot ? `"${workspaceRoot}" 기준 상대경로 / ` : ''}~ 로 바꿔 내보낼까요?\n(취소: 그대로 내보내기)`);
+          const count = await invoke<number>('export_ports', { filePath, portable, workspaceRoot });
+          showToast(`${count}개의 포트 정보를 내보냈습니다.`, 'success');
         }
       } else {
         // 브라우저에서는 파일 다운로드
@@ -2240,6 +2424,34 @@ function App() {
     }
   };
 
+  // 폴더의 docker-compose / Procfile / package.json workspaces 에서 프로젝트 일괄 등록
+  const handleDiscoverProjects = async () => {
+    if (!isTauri()) {
+      showToast('프로젝트 찾기는 Tauri 앱에서만 사용 가능합니다', 'error');
+      return;
+    }
+    try {
+      const { open } = await import('@tauri-apps/plugin-dialog');
+      const selected = await open({ directory: true, multiple: false });
+      if (!selected || typeof selected !== 'string') return;
+
+      const found = await API.discoverProjects(selected);
+      if (found.length === 0) {
+        showToast('새로 등록할 프로젝트를 찾지 못했습니다.', 'error');
+        return;
+      }
+      const list = found.map(d => `[${d.source}] ${d.portInfo.name}${d.portInfo.port ? ` :${d.portInfo.port}` : ''}`).join('\n');
+      if (!confirm(`${found.length}개 프로젝트를 찾았습니다.\n${list}\n\n추가할까요? (포트가 없는 항목은 빈 포트를 자동 할당)`)) return;
+
+      const merged = [...ports, ...found.map(d => d.portInfo)];
+      const saved = await invoke<{ ports: PortInfo[] }>('save_ports', { ports: merged, autoAssign: true });
+      setPorts(saved.ports);
+      showToast(`${found.length}개 프로젝트를 추가했습니다.`, 'success');
+    } catch (error) {
+      showToast('프로젝트 찾기 실패: ' + error, 'error');
+    }
+  };
+
   const handleImportPorts = async () => {
     try {
       if (isTauri()) {
@@ -2249,35 +2461,42 @@ function App() {
         const selected = await open({
           multiple: false,
           filters: [{
-            name: 'JSON',
-            extensions: ['json']
+            name: 'JSON / TOML',
+            extensions: ['json', 'toml']
           }]
         });
 
         if (selected && typeof selected === 'string') {
-          // Rust의 import_ports_from_file를 사용하여 파일 읽기
-          const importedPorts = await API.importPorts(selected);
-
-          if (importedPorts.length > 0) {
-            const existingIds = new Set(ports.map(p => p.id));
-            const newPorts = importedPorts.filter(p => !existingIds.has(p.id));
+          // Rust에서 기존 목록과 병합 계획을 먼저 계산 (저장 전 미리보기)
+          // 공유용으로 내보낸 상대경로는 첫 작업 루트 기준으로 복원
+          const workspaceRoot = workspaceRoots.find(r => r.path.startsWith('/'))?.path;
+          let strategy: ImportStrategy = 'skip-existing';
+          let preview = await API.previewImportPorts(selected, strategy, workspaceRoot);
 
-            if (newPorts.length > 0) {
-              const updatedPorts = [...ports, ...newPorts];
-              setPorts(updatedPorts);
-
-              // 명시적으로 저장
-              if (import.meta.env.DEV) console.log('[Import] Explicitly saving ports after import');
-              await API.savePorts(updatedPorts);
-              if (import.meta.env.DEV) console.log('[Import] Ports saved successfully');
-
-              showToast(`${newPorts.length}개의 포트 정보를 불러왔습니다.`, 'success');
-            } else {
-              showToast('새로운 포트 정보가 없습니다. (모두 이미 등록되어 있음)', 'error');
-            }
-          } else {
+          if (preview.total === 0) {
             showToast('불러온 파일에 포트 정보가 없습니다.', 'error');
+            return;
+          }
+          if (preview.skipped.length > 0 && confirm(`이미 등록된 ${preview.skipped.length}개 항목이 있습니다.\n불러온 값으로 덮어쓸까요? (취소: 건너뛰기)`)) {
+            strategy = 'overwrite';
+            preview = await API.previewImportPorts(selected, strategy, workspaceRoot);
+          }
+          if (preview.added.length === 0 && preview.changed.length === 0) {
+            showToast('새로운 포트 정보가 없습니다. (모두 이미 등록되어 있음)', 'error');
+            return;
           }
+
+          const summary = [
+            `추가 ${preview.added.length}개, 변경 ${preview.changed.length}개, 건너뜀 ${preview.skipped.length}개`,
+            ...preview.renamed.map(r => `id 변경: ${r.name} (${r.originalId} → ${r.id})`),
+            ...preview.conflicts.map(c => `⚠ ${c.message}`),
+          ].join('\n');
+          if (!confirm(`${summary}\n\n불러올까요?`)) return;
+
+          const result = await API.applyImportPorts(selected, strategy, workspaceRoot);
+          setPorts(result.ports);
+          if (import.meta.env.DEV) console.log('[Import] Applied import', result);
+          showToast(`${result.added.length + result.changed.length}개의 포트 정보를 불러왔습니다.`, 'success');
         }
       } else {
         // 브라우저: FileReader 사용
@@ -2731,10 +2950,12 @@ function App() {
     setViewingPortName(portName);
     setPortLogs([]);
     portLogOffsetRef.current = 0;
+    portLogFingerprintRef.current = undefined;
     setShowPortLog(true);
     setIsLoadingPortLog(true);
 
     // Clear any existing polling
+    portLogPullRef.current = null;
     if (portLogPollingRef.current) {
       clearInterval(portLogPollingRef.current);
       portLogPollingRef.current = null;
@@ -2748,23 +2969,26 @@ function App() {
       } else {
         const lines = data.content.split('\n').filter((l: string) => l.length > 0);
         setPortLogs(lines.length > 0 ? lines : ['(로그가 비어 있습니다)']);
-        portLogOffsetRef.current = data.size;
+        portLogOffsetRef.current = data.nextOffset;
+        portLogFingerprintRef.current = data.fingerprint;
       }
       setIsLoadingPortLog(false);
 
-      // Start polling for new content
+      // 새 내용 읽기 — Tauri 는 watcher 의 log-appended 이벤트마다, 폴링은 놓친 이벤트 대비
       const MAX_LOG_LINES = 500;
-      portLogPollingRef.current = setInterval(async () => {
+      let pulling = false;
+      const pull = async () => {
+        if (pulling) return; // 이벤트와 폴링이 겹치면 같은 구간을 두 번 붙이지 않도록
+        pulling = true;
         try {
-          const newData = await API.readLogContent(portId, portLogOffsetRef.current);
+          const newData = await API.readLogContent(portId, portLogOffsetRef.current, portLogFingerprintRef.current);
           if (!newData.exists) return;
-          // 파일 재생성 감지 (서버 재시작 등으로 size가 줄어든 경우)
-          if (newData.size < portLogOffsetRef.current) {
-            portLogOffsetRef.current = 0;
-            const allData = await API.readLogContent(portId, 0);
-            const lines = allData.content.split('\n').filter((l: string) => l.length > 0);
+          // 파일 재생성 / rotation 감지 — 서버가 이미 새 파일의 끝부분을 돌려줌
+          if (newData.truncated) {
+            const lines = newData.content.split('\n').filter((l: string) => l.length > 0);
             setPortLogs(lines.slice(-MAX_LOG_LINES));
-            portLogOffsetRef.current = allData.size;
+            portLogOffsetRef.current = newData.nextOffset;
+            portLogFingerprintRef.current = newData.fingerprint;
             return;
           }
           if (newData.content && newData.content.length > 0) {
@@ -2774,13 +2998,18 @@ function App() {
                 const combined = [...prev, ...newLines];
                 return combined.length > MAX_LOG_LINES ? combined.slice(-MAX_LOG_LINES) : combined;
               });
-              portLogOffsetRef.current = newData.size;
             }
           }
+          portLogOffsetRef.current = newData.nextOffset;
+          portLogFingerprintRef.current = newData.fingerprint;
         } catch (e) {
           // Ignore transient polling errors
+        } finally {
+          pulling = false;
         }
-      }, 1000);
+      };
+      portLogPullRef.current = { portId, pull };
+      portLogPollingRef.current = setInterval(pull, isTauri() ? 5000 : 1000);
     } catch (error) {
       setPortLogs([`로그 읽기 실패: ${error}`]);
       setIsLoadingPortLog(false);
@@ -2791,6 +3020,7 @@ function App() {
     setShowPortLog(false);
     setViewingPortId(null);
     setViewingPortName('');
+    portLogPullRef.current = null;
     if (portLogPollingRef.current) {
       clearInterval(portLogPollingRef.current);
       portLogPollingRef.current = null;
@@ -3440,6 +3670,15 @@ function App() {
           <span style={{width:7,height:7,borderRadius:4,flexShrink:0,background:item.isRunning?'#8fb96e':'#6b6459'}} />
           <span style={{fontSize:13,fontWeight:600,letterSpacing:-0.2,color:'#ede7dd',flex:1,overflow:'hidden',textOverflow:'ellipsis',whiteSpace:'nowrap'}}>{item.name}</span>
           {item.favorite && <Star style={{width:10,height:10,flexShrink:0,fill:'#e8a557',color:'#e8a557'}} />}
+          {logErrors[item.id] && (
+            <span
+              title={`${logErrors[item.id].line}\n(클릭하면 닫기)`}
+              onClick={e => { e.stopPropagation(); API.clearLogError(item.id).catch(() => {}); }}
+              style={{fontSize:10,padding:'1px 6px',borderRadius:4,background:'rgba(201,106,90,0.14)',color:'#c96a5a',flexShrink:0,border:'1px solid rgba(201,106,90,0.3)',cursor:'pointer'}}
+            >
+              {LOG_ERROR_LABELS[logErrors[item.id].kind] ?? '에러'}
+            </span>
+          )}
           {item.port
             ? <span style={{fontSize:11,fontFamily:'JetBrains Mono, monospace',color:'#e8a557',flexShrink:0}}>:{item.port}</span>
             : item.folderPath && <span style={{fontSize:10,padding:'1px 6px',borderRadius:4,background:'rgba(232,165,87,0.12)',color:'#e8a557',flexShrink:0,border:'1px solid rgba(232,165,87,0.25)'}}>폴더</span>}
@@ -4452,6 +4691,7 @@ function App() {
                     onClick={() => {
                       setPortLogs([]);
                       portLogOffsetRef.current = 0;
+                      portLogFingerprintRef.current = undefined;
                       handleViewPortLog(viewingPortId!, viewingPortName);
                     }}
                     className="px-3 py-1.5 bg-[#221f1b] hover:bg-[#2a2520] text-[#ede7dd]/90 text-xs rounded-lg transition-colors flex items-center gap-1.5"
@@ -4660,6 +4900,11 @@ function App() {
                 <button data-help-key="btn-import-ports" onClick={handleImportPorts} title="불러오기" className="p-2 bg-[#1c1916] hover:bg-[#221f1b] text-zinc-500 hover:text-[#ede7dd]/90 rounded-xl border border-stone-800/40 hover:border-stone-700/60 transition-all">
                   <Download className="w-4 h-4" />
                 </button>
+                {isTauri() && (
+                  <button data-help-key="btn-discover-projects" onClick={handleDiscoverProjects} title="폴더에서 프로젝트 찾기" className="p-2 bg-[#1c1916] hover:bg-[#221f1b] text-zinc-500 hover:text-[#ede7dd]/90 rounded-xl border border-stone-800/40 hover:border-stone-700/60 transition-all">
+                    <Package className="w-4 h-4" />
+                  </button>
+                )}
                 <button data-help-key="btn-refresh" onClick={handleRefresh} disabled={isRefreshing || isAiEnriching} title={isAiEnriching ? 'AI 분석 중…' : '새로고침'} className="p-2 bg-[#1c1916] hover:bg-[#221f1b] text-zinc-500 hover:text-[#ede7dd]/90 rounded-xl border border-stone-800/40 hover:border-stone-700/60 transition-all disabled:opacity-50 disabled:cursor-not-allowed">
                   <RefreshCw className={`w-4 h-4 ${isRefreshing || isAiEnriching ? 'animate-spin' : ''}`} />
                 </button>
@@ -4962,7 +5207,7 @@ function App() {
                 const next = { ...existingObj, supabaseUrl, supabaseAnonKey, deviceName, ...(finalDeviceId ? { deviceId: finalDeviceId } : {}) };
                 if (isTauri()) {
                   const { invoke } = await import('@tauri-apps/api/core');
-                  await invoke('save_portal', { data: JSON.stringify(next) });
+                  await invoke('save_portal', { data: next });
                 } else {
                   await fetch('http://127.0.0.1:3001/api/portal', { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify(next) });
                 }
@@ -68,6 +68,10 @@ export const GUIDE_CONTENT: Record<string, GuideEntry> = {
     title: '프로젝트 불러오기',
     body: 'JSON 파일에서 포트 목록을 복원해요.',
   },
+  'btn-discover-projects': {
+    title: '폴더에서 프로젝트 찾기',
+    body: 'docker-compose, Procfile, package.json workspaces를 읽어 서비스/패키지를 한 번에 등록해요.',
+  },
   'btn-portal-import': {
     title: '북마크 불러오기',
     body: 'JSON 파일을 불러와서 북마크를 복원해요.',